@@ -0,0 +1,11 @@
+use common::{Part, Solution, Strictness};
+
+/// Parses and solves both parts of arbitrary bytes, which may fail but must not panic.
+pub fn solve<S: Solution>(data: &[u8]) {
+    let input = String::from_utf8_lossy(data);
+    for part in Part::ALL {
+        for strictness in [Strictness::Lenient, Strictness::Strict] {
+            let _ = S::solve_with(part, &input, strictness);
+        }
+    }
+}
@@ -0,0 +1,113 @@
+use std::{collections::BTreeMap, path::PathBuf};
+
+use anyhow::Context;
+use common::Part;
+
+use crate::{input::workspace_root, records::load_records};
+
+const HEADER: &str = "# day\tpart\tinput\tanswer";
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Key {
+    pub day: u8,
+    pub part: Part,
+    pub input: String,
+}
+
+/// Confirmed answers, stored one per line as tab separated `day part input answer`.
+#[derive(Debug)]
+pub struct Answers {
+    path: PathBuf,
+    entries: BTreeMap<Key, String>,
+}
+
+impl Answers {
+    pub fn default_path() -> PathBuf {
+        workspace_root().join("answers.txt")
+    }
+
+    /// Loads the answers file, a missing file is treated as having no answers yet.
+    pub fn load(path: impl Into<PathBuf>) -> anyhow::Result<Self> {
+        let path = path.into();
+        let entries = load_records(&path, parse_line)?.into_iter().collect();
+
+        Ok(Answers { path, entries })
+    }
+
+    pub fn get(&self, day: u8, part: Part, input: &str) -> Option<&str> {
+        let key = Key {
+            day,
+            part,
+            input: input.to_owned(),
+        };
+        self.entries.get(&key).map(String::as_str)
+    }
+
+    /// Records `answer`, returning the answer it replaced if there was one.
+    pub fn record(&mut self, key: Key, answer: String) -> Option<String> {
+        self.entries.insert(key, answer)
+    }
+
+    pub fn save(&self) -> anyhow::Result<()> {
+        let mut contents = format!("{HEADER}\n");
+        for (key, answer) in &self.entries {
+            contents.push_str(&format!(
+                "{}\t{}\t{}\t{answer}\n",
+                key.day, key.part, key.input
+            ));
+        }
+
+        std::fs::write(&self.path, contents)
+            .with_context(|| format!("Unable to write {}", self.path.display()))
+    }
+}
+
+fn parse_line(line: &str) -> anyhow::Result<(Key, String)> {
+    let mut fields = line.split('\t');
+    let (Some(day), Some(part), Some(input), Some(answer), None) = (
+        fields.next(),
+        fields.next(),
+        fields.next(),
+        fields.next(),
+        fields.next(),
+    ) else {
+        anyhow::bail!("Expected 4 tab separated fields");
+    };
+
+    let key = Key {
+        day: day.parse().context("Invalid day")?,
+        part: Part::try_from(part.parse::<u8>().context("Invalid part")?)?,
+        input: input.to_owned(),
+    };
+
+    Ok((key, answer.to_owned()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::temp::TempPath;
+
+    #[test]
+    fn round_trips_through_file() {
+        let path = TempPath::new("answers.txt");
+        let mut answers = Answers::load(&*path).unwrap();
+        let key = Key {
+            day: 6,
+            part: Part::Two,
+            input: "input".into(),
+        };
+        assert_eq!(answers.record(key.clone(), "1670".into()), None);
+        answers.save().unwrap();
+
+        let reloaded = Answers::load(&*path).unwrap();
+        assert_eq!(reloaded.get(6, Part::Two, "input"), Some("1670"));
+        assert_eq!(reloaded.get(6, Part::One, "input"), None);
+    }
+
+    #[test]
+    fn rejects_malformed_lines() {
+        assert!(parse_line("6\t3\tinput\t1").is_err());
+        assert!(parse_line("6 2 input 1").is_err());
+    }
+}
@@ -0,0 +1,183 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{Display, Formatter},
+    hint::black_box,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use anyhow::Context;
+use common::{Solution, Strictness, normalize};
+use serde::{Deserialize, Serialize};
+
+use crate::input::workspace_root;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Stage {
+    Parse,
+    #[serde(rename = "part_1")]
+    Part1,
+    #[serde(rename = "part_2")]
+    Part2,
+}
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Stage::Parse => write!(f, "parse"),
+            Stage::Part1 => write!(f, "part 1"),
+            Stage::Part2 => write!(f, "part 2"),
+        }
+    }
+}
+
+/// Timing summary of one stage, in nanoseconds so the baseline file stays readable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Stats {
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub p95_ns: u64,
+}
+
+impl Stats {
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "Cannot summarise zero samples");
+        samples.sort();
+        let at = |quantile: f64| {
+            let index = ((samples.len() - 1) as f64 * quantile).round() as usize;
+            samples[index].as_nanos() as u64
+        };
+
+        Stats {
+            min_ns: at(0.0),
+            median_ns: at(0.5),
+            p95_ns: at(0.95),
+        }
+    }
+}
+
+/// Times `S`'s parse and both parts `iterations` times each against `input`.
+pub fn measure<S: Solution>(input: &str, iterations: usize) -> anyhow::Result<Vec<(Stage, Stats)>> {
+    let input = &normalize(S::DAY, input, Strictness::Lenient)?;
+    let parsed = S::parse(input)?;
+    Ok(vec![
+        (
+            Stage::Parse,
+            time(iterations, || S::parse(black_box(input)).map(drop))?,
+        ),
+        (
+            Stage::Part1,
+            time(iterations, || S::part_1(black_box(&parsed)).map(drop))?,
+        ),
+        (
+            Stage::Part2,
+            time(iterations, || S::part_2(black_box(&parsed)).map(drop))?,
+        ),
+    ])
+}
+
+fn time(iterations: usize, mut stage: impl FnMut() -> anyhow::Result<()>) -> anyhow::Result<Stats> {
+    let samples = (0..iterations.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            stage()?;
+            Ok(start.elapsed())
+        })
+        .collect::<anyhow::Result<_>>()?;
+
+    Ok(Stats::from_samples(samples))
+}
+
+/// Saved timings per day and stage that later runs are compared against.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Baseline {
+    pub days: BTreeMap<u8, BTreeMap<Stage, Stats>>,
+}
+
+impl Baseline {
+    pub fn default_path() -> PathBuf {
+        workspace_root().join("bench_baseline.json")
+    }
+
+    /// Loads a baseline, a missing file is treated as an empty baseline.
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        match std::fs::read_to_string(path) {
+            Ok(contents) => serde_json::from_str(&contents)
+                .with_context(|| format!("Unable to parse {}", path.display())),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Baseline::default()),
+            Err(e) => Err(e).with_context(|| format!("Unable to read {}", path.display())),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        let contents = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, contents + "\n")
+            .with_context(|| format!("Unable to write {}", path.display()))
+    }
+
+    pub fn get(&self, day: u8, stage: Stage) -> Option<Stats> {
+        self.days.get(&day)?.get(&stage).copied()
+    }
+
+    pub fn insert(&mut self, day: u8, stage: Stage, stats: Stats) {
+        self.days.entry(day).or_default().insert(stage, stats);
+    }
+}
+
+/// Relative change of the median against the baseline, `0.1` being 10% slower.
+pub fn change(baseline: Stats, current: Stats) -> f64 {
+    current.median_ns as f64 / baseline.median_ns.max(1) as f64 - 1.0
+}
+
+/// Formats nanoseconds with the largest unit that keeps the number readable.
+pub fn format_ns(ns: u64) -> String {
+    match ns {
+        0..1_000 => format!("{ns}ns"),
+        1_000..1_000_000 => format!("{:.1}µs", ns as f64 / 1e3),
+        1_000_000..1_000_000_000 => format!("{:.1}ms", ns as f64 / 1e6),
+        _ => format!("{:.2}s", ns as f64 / 1e9),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::temp::TempPath;
+
+    #[test]
+    fn summarises_samples() {
+        let samples = (1..=100).rev().map(Duration::from_nanos).collect();
+        assert_eq!(
+            Stats::from_samples(samples),
+            Stats {
+                min_ns: 1,
+                median_ns: 51,
+                p95_ns: 95,
+            }
+        );
+    }
+
+    #[test]
+    fn baseline_round_trips_and_flags_regressions() {
+        let path = TempPath::new("bench.json");
+        let stats = Stats {
+            min_ns: 90,
+            median_ns: 100,
+            p95_ns: 120,
+        };
+        let mut baseline = Baseline::default();
+        baseline.insert(6, Stage::Part2, stats);
+        baseline.save(&path).unwrap();
+
+        let reloaded = Baseline::load(&path).unwrap();
+        assert_eq!(reloaded.get(6, Stage::Part2), Some(stats));
+        assert_eq!(reloaded.get(6, Stage::Part1), None);
+
+        let slower = Stats {
+            median_ns: 125,
+            ..stats
+        };
+        assert!((change(stats, slower) - 0.25).abs() < 1e-9);
+    }
+}
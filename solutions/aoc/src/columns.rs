@@ -0,0 +1,58 @@
+use std::fmt::Write;
+
+use day_1::ColumnReport;
+
+use crate::table::Table;
+
+/// Lays out a column report as one table per score, with columns numbered from 1.
+pub fn render(report: &ColumnReport) -> String {
+    let mut out = String::new();
+    let sections = [
+        ("Distances", matrix(&report.distances, |d| d.to_string())),
+        (
+            "Similarities",
+            matrix(&report.similarities, |s| s.to_string()),
+        ),
+        ("Overlaps", matrix(&report.overlaps, |o| format!("{o:.3}"))),
+    ];
+    for (title, table) in sections {
+        let _ = writeln!(out, "{title}\n{table}");
+    }
+
+    if let (Some((a, b)), Some((c, d))) = (report.most_similar(), report.least_similar()) {
+        let _ = writeln!(out, "Most similar: columns {} and {}", a + 1, b + 1);
+        let _ = writeln!(out, "Least similar: columns {} and {}", c + 1, d + 1);
+    }
+    out
+}
+
+fn matrix<T>(rows: &[Vec<T>], cell: impl Fn(&T) -> String) -> Table {
+    let headers = std::iter::once(String::new()).chain((1..=rows.len()).map(|j| j.to_string()));
+    let mut table = Table::new(headers);
+    for (i, row) in rows.iter().enumerate() {
+        let label = (i + 1).to_string();
+        table.row(
+            std::iter::once(label)
+                .chain(row.iter().map(&cell))
+                .collect(),
+        );
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_a_table_per_score() {
+        let report = ColumnReport::new(&[vec![1, 2], vec![1, 3]]);
+        assert_eq!(
+            render(&report),
+            "Distances\n   1  2\n-  -  -\n1  0  1\n2  1  0\n\n\
+             Similarities\n   1  2\n-  -  -\n1  3  1\n2  1  4\n\n\
+             Overlaps\n   1      2\n-  -----  -----\n1  1.000  0.333\n2  0.333  1.000\n\n\
+             Most similar: columns 1 and 2\nLeast similar: columns 1 and 2\n"
+        );
+    }
+}
@@ -0,0 +1,134 @@
+use std::{
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use anyhow::Context;
+
+use crate::http::HttpClient;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const YEAR: u16 = 2024;
+
+/// Minimum gap between two requests to the puzzle server.
+const MIN_INTERVAL: Duration = Duration::from_secs(3);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fetched {
+    Cached,
+    Downloaded,
+}
+
+/// Downloads puzzle inputs, never requesting a day that is already cached on disk.
+pub struct Fetcher<C> {
+    client: C,
+    base_url: String,
+    session: String,
+    min_interval: Duration,
+    last_request: Option<Instant>,
+}
+
+impl<C: HttpClient> Fetcher<C> {
+    pub fn new(client: C, base_url: impl Into<String>, session: impl Into<String>) -> Self {
+        Fetcher {
+            client,
+            base_url: base_url.into().trim_end_matches('/').to_owned(),
+            session: session.into(),
+            min_interval: MIN_INTERVAL,
+            last_request: None,
+        }
+    }
+
+    #[cfg(test)]
+    pub fn with_min_interval(self, min_interval: Duration) -> Self {
+        Fetcher {
+            min_interval,
+            ..self
+        }
+    }
+
+    /// Fetches `day`'s input into `dest` unless a non-empty copy is already there.
+    pub fn fetch(&mut self, day: u8, dest: &Path) -> anyhow::Result<Fetched> {
+        if std::fs::metadata(dest).is_ok_and(|meta| meta.len() > 0) {
+            return Ok(Fetched::Cached);
+        }
+
+        let url = format!("{}/{YEAR}/day/{day}/input", self.base_url);
+        self.throttle();
+        let response = self
+            .client
+            .get(&url, &self.session)
+            .with_context(|| format!("Unable to reach {url}"))?;
+
+        match response.status {
+            200 => {}
+            400 | 401 | 500 => {
+                anyhow::bail!("{url} rejected the session token ({})", response.status)
+            }
+            404 => anyhow::bail!("Day {day}'s input is not available yet"),
+            429 => anyhow::bail!("Rate limited by {url}, try again later"),
+            status => anyhow::bail!("Unexpected {status} from {url}: {}", response.body.trim()),
+        }
+
+        if let Some(parent) = dest.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Unable to create {}", parent.display()))?;
+        }
+        std::fs::write(dest, response.body)
+            .with_context(|| format!("Unable to write {}", dest.display()))?;
+
+        Ok(Fetched::Downloaded)
+    }
+
+    fn throttle(&mut self) {
+        if let Some(last) = self.last_request {
+            let elapsed = last.elapsed();
+            if elapsed < self.min_interval {
+                std::thread::sleep(self.min_interval - elapsed);
+            }
+        }
+        self.last_request = Some(Instant::now());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        http::{UreqClient, mock::MockServer},
+        temp::TempPath,
+    };
+
+    fn fetcher(server: &MockServer) -> Fetcher<UreqClient> {
+        Fetcher::new(UreqClient::new(), &server.url, "secret").with_min_interval(Duration::ZERO)
+    }
+
+    #[test]
+    fn downloads_then_serves_from_cache() {
+        let server = MockServer::start(|_| (200, "1   2\n".into()));
+        let dir = TempPath::new("fetch-cache");
+        let dest = dir.join("inputs").join("input.txt");
+        let mut fetcher = fetcher(&server);
+
+        assert_eq!(fetcher.fetch(1, &dest).unwrap(), Fetched::Downloaded);
+        assert_eq!(fetcher.fetch(1, &dest).unwrap(), Fetched::Cached);
+        assert_eq!(std::fs::read_to_string(&dest).unwrap(), "1   2\n");
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(requests[0].path, "/2024/day/1/input");
+        assert_eq!(requests[0].header("Cookie"), Some("session=secret"));
+    }
+
+    #[test]
+    fn errors_do_not_write_a_cache_file() {
+        let server = MockServer::start(|_| (404, "Not found".into()));
+        let dir = TempPath::new("fetch-missing");
+        let dest = dir.join("inputs").join("input.txt");
+
+        let error = fetcher(&server).fetch(25, &dest).unwrap_err();
+        assert!(error.to_string().contains("not available"));
+        assert!(!dest.exists());
+    }
+}
@@ -0,0 +1,160 @@
+/// A response from the puzzle server, error statuses included.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+/// The HTTP calls the runner needs, so tests can swap in a local server.
+pub trait HttpClient {
+    fn get(&self, url: &str, session: &str) -> anyhow::Result<Response>;
+
+    fn post(&self, url: &str, session: &str, form: &[(&str, &str)]) -> anyhow::Result<Response>;
+}
+
+const USER_AGENT: &str = "github.com/zack37/advent_of_code_2024";
+
+/// Blocking client backed by `ureq`.
+pub struct UreqClient {
+    agent: ureq::Agent,
+}
+
+impl UreqClient {
+    pub fn new() -> Self {
+        UreqClient {
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+        }
+    }
+}
+
+fn into_response(result: Result<ureq::Response, ureq::Error>) -> anyhow::Result<Response> {
+    let response = match result {
+        Ok(response) => response,
+        Err(ureq::Error::Status(_, response)) => response,
+        Err(e) => return Err(e.into()),
+    };
+
+    Ok(Response {
+        status: response.status(),
+        body: response.into_string()?,
+    })
+}
+
+impl HttpClient for UreqClient {
+    fn get(&self, url: &str, session: &str) -> anyhow::Result<Response> {
+        into_response(
+            self.agent
+                .get(url)
+                .set("Cookie", &format!("session={session}"))
+                .call(),
+        )
+    }
+
+    fn post(&self, url: &str, session: &str, form: &[(&str, &str)]) -> anyhow::Result<Response> {
+        into_response(
+            self.agent
+                .post(url)
+                .set("Cookie", &format!("session={session}"))
+                .send_form(form),
+        )
+    }
+}
+
+#[cfg(test)]
+pub mod mock {
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        sync::{Arc, Mutex},
+    };
+
+    #[derive(Debug, Clone)]
+    pub struct Request {
+        pub method: String,
+        pub path: String,
+        pub headers: Vec<(String, String)>,
+        pub body: String,
+    }
+
+    impl Request {
+        pub fn header(&self, name: &str) -> Option<&str> {
+            self.headers
+                .iter()
+                .find(|(key, _)| key.eq_ignore_ascii_case(name))
+                .map(|(_, value)| value.as_str())
+        }
+    }
+
+    /// A throwaway HTTP server on localhost that answers every request with `handler`.
+    pub struct MockServer {
+        pub url: String,
+        requests: Arc<Mutex<Vec<Request>>>,
+    }
+
+    impl MockServer {
+        pub fn start(handler: impl Fn(&Request) -> (u16, String) + Send + 'static) -> Self {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let url = format!("http://{}", listener.local_addr().unwrap());
+            let requests = Arc::new(Mutex::new(vec![]));
+            let seen = requests.clone();
+
+            std::thread::spawn(move || {
+                for stream in listener.incoming() {
+                    let mut stream = stream.unwrap();
+                    let request = read_request(&mut BufReader::new(&stream));
+                    let (status, body) = handler(&request);
+                    seen.lock().unwrap().push(request);
+                    write!(
+                        stream,
+                        "HTTP/1.1 {status} Mock\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                        body.len()
+                    )
+                    .unwrap();
+                }
+            });
+
+            MockServer { url, requests }
+        }
+
+        pub fn requests(&self) -> Vec<Request> {
+            self.requests.lock().unwrap().clone()
+        }
+    }
+
+    fn read_request(reader: &mut impl BufRead) -> Request {
+        let mut line = String::new();
+        reader.read_line(&mut line).unwrap();
+        let mut parts = line.split_whitespace();
+        let method = parts.next().unwrap_or_default().to_owned();
+        let path = parts.next().unwrap_or_default().to_owned();
+
+        let mut headers = vec![];
+        loop {
+            let mut line = String::new();
+            reader.read_line(&mut line).unwrap();
+            let line = line.trim_end();
+            if line.is_empty() {
+                break;
+            }
+            if let Some((key, value)) = line.split_once(':') {
+                headers.push((key.trim().to_owned(), value.trim().to_owned()));
+            }
+        }
+
+        let mut request = Request {
+            method,
+            path,
+            headers,
+            body: String::new(),
+        };
+        let length = request
+            .header("Content-Length")
+            .and_then(|length| length.parse().ok())
+            .unwrap_or(0);
+        let mut body = vec![0; length];
+        reader.read_exact(&mut body).unwrap();
+        request.body = String::from_utf8(body).unwrap();
+
+        request
+    }
+}
@@ -0,0 +1,140 @@
+use std::{
+    fmt::{Display, Formatter},
+    io::Read,
+    path::{Path, PathBuf},
+    sync::LazyLock,
+};
+
+use anyhow::Context;
+
+/// Where a day's puzzle input is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Stdin,
+    File(PathBuf),
+}
+
+impl Source {
+    /// Picks the input for `day`: an explicit `--input` path (`-` meaning stdin) wins,
+    /// then `day_N.txt` inside the user's input directory, then the day's checked-in
+    /// `inputs/input.txt`.
+    pub fn resolve(day: u8, input: Option<PathBuf>, input_dir: Option<&Path>) -> Self {
+        match (input, input_dir) {
+            (Some(path), _) if path.as_os_str() == "-" => Source::Stdin,
+            (Some(path), _) => Source::File(path),
+            (None, Some(dir)) => Source::File(dir.join(format!("day_{day}.txt"))),
+            (None, None) => Source::File(default_path(day)),
+        }
+    }
+
+    /// Short name used to key recorded answers, the file stem for file inputs.
+    pub fn name(&self) -> String {
+        match self {
+            Source::Stdin => "stdin".into(),
+            Source::File(path) => path
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().into_owned())
+                .unwrap_or_else(|| path.display().to_string()),
+        }
+    }
+
+    /// Where the input comes from, for reports: `stdin`, or the file's full path when it
+    /// exists.
+    pub fn location(&self) -> String {
+        match self {
+            Source::Stdin => "stdin".into(),
+            Source::File(path) => path
+                .canonicalize()
+                .unwrap_or_else(|_| path.clone())
+                .display()
+                .to_string(),
+        }
+    }
+
+    pub fn read(&self) -> anyhow::Result<String> {
+        match self {
+            Source::Stdin => {
+                let mut input = String::new();
+                std::io::stdin()
+                    .read_to_string(&mut input)
+                    .context("Unable to read input from stdin")?;
+                Ok(input)
+            }
+            Source::File(path) => std::fs::read_to_string(path)
+                .with_context(|| format!("Unable to read input from {}", path.display())),
+        }
+    }
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Source::Stdin => write!(f, "<stdin>"),
+            Source::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+/// The repository root, resolved once so the paths built from it print without `..`.
+static WORKSPACE_ROOT: LazyLock<PathBuf> = LazyLock::new(|| {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..").join("..");
+    root.canonicalize().unwrap_or(root)
+});
+
+pub fn workspace_root() -> PathBuf {
+    WORKSPACE_ROOT.clone()
+}
+
+/// The `solutions/day_N` crate directory for `day`.
+pub fn day_dir(day: u8) -> PathBuf {
+    workspace_root()
+        .join("solutions")
+        .join(format!("day_{day}"))
+}
+
+pub fn default_path(day: u8) -> PathBuf {
+    day_dir(day).join("src").join("inputs").join("input.txt")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn explicit_path_wins() {
+        let source = Source::resolve(3, Some("other.txt".into()), Some(Path::new("dir")));
+        assert_eq!(source, Source::File("other.txt".into()));
+    }
+
+    #[test]
+    fn dash_reads_stdin() {
+        assert_eq!(Source::resolve(3, Some("-".into()), None), Source::Stdin);
+    }
+
+    #[test]
+    fn input_dir_is_keyed_by_day() {
+        let source = Source::resolve(3, None, Some(Path::new("inputs")));
+        assert_eq!(source, Source::File(Path::new("inputs").join("day_3.txt")));
+    }
+
+    #[test]
+    fn name_is_file_stem() {
+        assert_eq!(Source::resolve(3, None, None).name(), "input");
+        assert_eq!(Source::Stdin.name(), "stdin");
+    }
+
+    #[test]
+    fn location_is_the_full_path() {
+        let location = Source::resolve(3, None, None).location();
+        assert!(!location.contains(".."));
+        assert!(location.ends_with("input.txt"));
+        assert_eq!(Source::Stdin.location(), "stdin");
+    }
+
+    #[test]
+    fn missing_file_is_an_error() {
+        let source = Source::File("does/not/exist.txt".into());
+        let error = source.read().unwrap_err();
+        assert!(error.to_string().contains("does/not/exist.txt"));
+    }
+}
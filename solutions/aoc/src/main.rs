@@ -0,0 +1,785 @@
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+    process::{self, Stdio},
+    thread,
+    time::{Duration, Instant, SystemTime},
+};
+
+use anyhow::Context;
+use clap::{Args, Parser, Subcommand};
+use common::{Answer, Part, Strictness};
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+
+use crate::{
+    answers::{Answers, Key},
+    bench::Baseline,
+    fetch::{DEFAULT_BASE_URL, Fetched, Fetcher},
+    http::UreqClient,
+    input::Source,
+    registry::Day,
+    report::{Format, Record},
+    submit::{History, Submitter, Verdict},
+    table::Table,
+    watch::{Change, Snapshot},
+};
+
+mod answers;
+mod bench;
+mod columns;
+mod fetch;
+mod http;
+mod input;
+mod records;
+mod registry;
+mod report;
+mod scaffold;
+mod submit;
+mod table;
+#[cfg(test)]
+mod temp;
+mod watch;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2024 solution runner")]
+struct Cli {
+    /// File of confirmed answers used by `verify` and `record`
+    #[arg(long, global = true, env = "AOC_ANSWERS")]
+    answers: Option<PathBuf>,
+    /// File of past submissions used by `submit`
+    #[arg(long, global = true, env = "AOC_SUBMISSIONS")]
+    submissions: Option<PathBuf>,
+    /// Reject blank lines after the end of the input instead of ignoring them
+    #[arg(long, global = true)]
+    strict: bool,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Args)]
+struct InputArgs {
+    /// Input file to solve, `-` reads from stdin
+    #[arg(short, long)]
+    input: Option<PathBuf>,
+    /// Directory holding `day_N.txt` inputs, used when `--input` is omitted
+    #[arg(long, env = "AOC_INPUT_DIR")]
+    input_dir: Option<PathBuf>,
+}
+
+impl InputArgs {
+    fn source(self, day: u8) -> Source {
+        Source::resolve(day, self.input, self.input_dir.as_deref())
+    }
+}
+
+#[derive(Args)]
+struct ServerArgs {
+    /// Session cookie of the logged in puzzle account
+    #[arg(long, env = "AOC_SESSION", hide_env_values = true)]
+    session: String,
+    /// Base URL of the puzzle server
+    #[arg(long, env = "AOC_BASE_URL", default_value = DEFAULT_BASE_URL)]
+    base_url: String,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run a day's solution against its puzzle input
+    Run {
+        #[arg(short, long, required_unless_present = "all")]
+        day: Option<u8>,
+        /// Part to run, both parts are run when omitted
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Run every registered day and summarize the answers in a table
+        #[arg(long, conflicts_with_all = ["day", "part", "input"])]
+        all: bool,
+        /// Solve the days of `--all` on a thread pool
+        #[arg(long, requires = "all")]
+        parallel: bool,
+        #[command(flatten)]
+        input: InputArgs,
+        /// How to print the answers
+        #[arg(long, value_enum, default_value_t)]
+        format: Format,
+    },
+    /// Check every day's answers against the recorded ones
+    Verify {
+        /// Only verify this day
+        #[arg(short, long)]
+        day: Option<u8>,
+        /// Directory holding `day_N.txt` inputs
+        #[arg(long, env = "AOC_INPUT_DIR")]
+        input_dir: Option<PathBuf>,
+    },
+    /// Record a confirmed answer, solving for it when `--answer` is omitted
+    Record {
+        #[arg(short, long)]
+        day: u8,
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        #[arg(short, long)]
+        answer: Option<String>,
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// Download puzzle inputs that are not cached yet
+    Fetch {
+        /// Only fetch this day, every registered day is fetched when omitted
+        #[arg(short, long)]
+        day: Option<u8>,
+        #[command(flatten)]
+        server: ServerArgs,
+    },
+    /// Time each day's parse and parts, comparing against a saved baseline
+    Bench {
+        /// Only benchmark this day
+        #[arg(short, long)]
+        day: Option<u8>,
+        /// Times each stage is run
+        #[arg(short = 'n', long, default_value_t = 10)]
+        iterations: usize,
+        /// Median slowdown, in percent, reported as a regression
+        #[arg(short, long, default_value_t = 10.0)]
+        threshold: f64,
+        /// Baseline JSON file to compare against
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+        /// Overwrite the baseline with this run's timings
+        #[arg(long)]
+        save: bool,
+        /// Directory holding `day_N.txt` inputs
+        #[arg(long, env = "AOC_INPUT_DIR")]
+        input_dir: Option<PathBuf>,
+    },
+    /// Generate a new day's crate and register it with the runner
+    New {
+        #[arg(short, long)]
+        day: u8,
+    },
+    /// Print a random puzzle input for a day
+    Generate {
+        #[arg(short, long)]
+        day: u8,
+        /// Seed for the random generator, the same seed always gives the same input
+        #[arg(short, long, default_value_t = 0)]
+        seed: u64,
+        /// How big to make the input, such as the number of lines or the side of a grid
+        #[arg(long, default_value_t = 100)]
+        size: usize,
+    },
+    /// Re-run a day's sample tests and input whenever its sources or input change
+    Watch {
+        #[arg(short, long)]
+        day: u8,
+        #[command(flatten)]
+        input: InputArgs,
+        /// Milliseconds between checks for changes
+        #[arg(long, default_value_t = 500)]
+        interval: u64,
+    },
+    /// Compare every pair of columns in day 1 style side by side location lists
+    Columns {
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// Submit an answer to the puzzle server, solving for it when `--answer` is omitted
+    Submit {
+        #[arg(short, long)]
+        day: u8,
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        #[arg(short, long)]
+        answer: Option<String>,
+        #[command(flatten)]
+        input: InputArgs,
+        #[command(flatten)]
+        server: ServerArgs,
+    },
+}
+
+fn main() -> anyhow::Result<()> {
+    let cli = Cli::parse();
+    let answers_path = cli.answers.unwrap_or_else(Answers::default_path);
+    let submissions_path = cli.submissions.unwrap_or_else(History::default_path);
+    let strictness = if cli.strict {
+        Strictness::Strict
+    } else {
+        Strictness::Lenient
+    };
+
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            all: _,
+            parallel,
+            input,
+            format,
+        } => match day {
+            Some(day) => run(day, part, input.source(day), strictness, format),
+            None => run_all(
+                parallel,
+                input.input_dir,
+                strictness,
+                format,
+                Answers::load(answers_path)?,
+            ),
+        },
+        Command::Verify { day, input_dir } => {
+            verify(day, input_dir, strictness, Answers::load(answers_path)?)
+        }
+        Command::Record {
+            day,
+            part,
+            answer,
+            input,
+        } => {
+            let part = Part::try_from(part)?;
+            let source = input.source(day);
+            let answer = answer_or_solve(day, part, answer, &source, strictness)?;
+            record(
+                day,
+                part,
+                answer,
+                source.name(),
+                Answers::load(answers_path)?,
+            )
+        }
+        Command::Fetch { day, server } => fetch(day, server),
+        Command::Bench {
+            day,
+            iterations,
+            threshold,
+            baseline,
+            save,
+            input_dir,
+        } => bench(
+            day,
+            iterations,
+            threshold / 100.0,
+            baseline.unwrap_or_else(Baseline::default_path),
+            save,
+            input_dir,
+        ),
+        Command::New { day } => new(day),
+        Command::Generate { day, seed, size } => generate(day, seed, size),
+        Command::Watch {
+            day,
+            input,
+            interval,
+        } => watch(
+            day,
+            input.source(day),
+            strictness,
+            Duration::from_millis(interval),
+        ),
+        Command::Columns { input } => compare_columns(input.source(1)),
+        Command::Submit {
+            day,
+            part,
+            answer,
+            input,
+            server,
+        } => {
+            let part = Part::try_from(part)?;
+            let source = input.source(day);
+            let answer = answer_or_solve(day, part, answer, &source, strictness)?;
+            submit(
+                day,
+                part,
+                answer,
+                source.name(),
+                server,
+                History::load(submissions_path)?,
+                Answers::load(answers_path)?,
+            )
+        }
+    }
+}
+
+fn find_day(day: u8) -> anyhow::Result<&'static Day> {
+    registry::find(day).ok_or_else(|| anyhow::anyhow!("Day {day} has no registered solution"))
+}
+
+fn run(
+    day: u8,
+    part: Option<u8>,
+    source: Source,
+    strictness: Strictness,
+    format: Format,
+) -> anyhow::Result<()> {
+    let entry = find_day(day)?;
+    let input = source.read()?;
+
+    let parts = match part {
+        Some(part) => vec![Part::try_from(part)?],
+        None => Part::ALL.to_vec(),
+    };
+
+    for part in parts {
+        let start = Instant::now();
+        let answer = (entry.solve)(part, &input, strictness)?;
+        let elapsed_ns = start.elapsed().as_nanos() as u64;
+        let record = Record::new(day, part, &answer, &source, elapsed_ns);
+        println!("{}", record.render(format)?);
+    }
+
+    Ok(())
+}
+
+fn verify(
+    day: Option<u8>,
+    input_dir: Option<PathBuf>,
+    strictness: Strictness,
+    answers: Answers,
+) -> anyhow::Result<()> {
+    let days = match day {
+        Some(day) => vec![find_day(day)?],
+        None => registry::DAYS.iter().collect(),
+    };
+
+    let mut table = Table::new(["Day", "Part", "Input", "Expected", "Actual", "Status"]);
+    let mut failures = 0;
+    for entry in days {
+        let source = Source::resolve(entry.day, None, input_dir.as_deref());
+        let name = source.name();
+        let input = source.read();
+
+        for part in Part::ALL {
+            let expected = answers.get(entry.day, part, &name);
+            let actual = match &input {
+                Ok(input) => (entry.solve)(part, input, strictness),
+                Err(e) => Err(anyhow::anyhow!("{e}")),
+            };
+
+            let (actual, status) = check(&actual, expected);
+            if is_failure(&status) {
+                failures += 1;
+            }
+
+            table.row(vec![
+                entry.day.to_string(),
+                part.to_string(),
+                name.clone(),
+                expected.unwrap_or_default().to_owned(),
+                actual,
+                status,
+            ]);
+        }
+    }
+
+    print!("{table}");
+
+    if failures > 0 {
+        anyhow::bail!("{failures} answer(s) failed verification");
+    }
+
+    Ok(())
+}
+
+/// The answer to show for a solved part, and how it compares with the recorded one.
+fn check(actual: &anyhow::Result<Answer>, expected: Option<&str>) -> (String, String) {
+    match (actual, expected) {
+        (Err(e), _) => (String::new(), format!("error: {e}")),
+        (Ok(actual), None) => (actual.to_string(), "unrecorded".into()),
+        (Ok(actual), Some(expected)) if actual.to_string() == expected => {
+            (actual.to_string(), "ok".into())
+        }
+        (Ok(actual), Some(_)) => (actual.to_string(), "MISMATCH".into()),
+    }
+}
+
+fn is_failure(status: &str) -> bool {
+    status != "ok" && status != "unrecorded"
+}
+
+/// One part of a day solved by `run --all`.
+struct Solved {
+    day: u8,
+    part: Part,
+    source: Source,
+    answer: anyhow::Result<Answer>,
+    elapsed_ns: u64,
+}
+
+fn run_all(
+    parallel: bool,
+    input_dir: Option<PathBuf>,
+    strictness: Strictness,
+    format: Format,
+    answers: Answers,
+) -> anyhow::Result<()> {
+    let start = Instant::now();
+    let inputs: Vec<_> = registry::DAYS
+        .iter()
+        .map(|entry| {
+            let source = Source::resolve(entry.day, None, input_dir.as_deref());
+            let input = source.read();
+            (entry, source, input)
+        })
+        .collect();
+    let jobs: Vec<_> = inputs
+        .iter()
+        .flat_map(|input| Part::ALL.map(|part| (input, part)))
+        .collect();
+
+    // One thread solves the days in order, zero lets rayon use every core.
+    let threads = if parallel { 0 } else { 1 };
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(threads)
+        .build()?;
+    let solved: Vec<_> = pool.install(|| {
+        jobs.par_iter()
+            .map(|&((entry, source, input), part)| {
+                let start = Instant::now();
+                let answer = match input {
+                    Ok(input) => (entry.solve)(part, input, strictness),
+                    Err(e) => Err(anyhow::anyhow!("{e}")),
+                };
+                Solved {
+                    day: entry.day,
+                    part,
+                    source: source.clone(),
+                    answer,
+                    elapsed_ns: start.elapsed().as_nanos() as u64,
+                }
+            })
+            .collect()
+    });
+    let total_ns = start.elapsed().as_nanos() as u64;
+
+    let mut table = Table::new(["Day", "Part", "Input", "Answer", "Time", "Status"]);
+    let mut failures = 0;
+    for solved in &solved {
+        let expected = answers.get(solved.day, solved.part, &solved.source.name());
+        let (answer, status) = check(&solved.answer, expected);
+        if is_failure(&status) {
+            failures += 1;
+        }
+
+        match (format, &solved.answer) {
+            (Format::Text, _) => table.row(vec![
+                solved.day.to_string(),
+                solved.part.to_string(),
+                solved.source.name(),
+                answer,
+                bench::format_ns(solved.elapsed_ns),
+                status,
+            ]),
+            (Format::Json, Ok(answer)) => {
+                let record = Record::new(
+                    solved.day,
+                    solved.part,
+                    answer,
+                    &solved.source,
+                    solved.elapsed_ns,
+                );
+                println!("{}", record.render(format)?);
+            }
+            (Format::Json, Err(_)) => {
+                eprintln!("Day {} part {}: {status}", solved.day, solved.part)
+            }
+        }
+    }
+
+    if format == Format::Text {
+        table.row(vec![
+            "Total".into(),
+            String::new(),
+            String::new(),
+            String::new(),
+            bench::format_ns(total_ns),
+            String::new(),
+        ]);
+        print!("{table}");
+    }
+
+    if failures > 0 {
+        anyhow::bail!("{failures} answer(s) failed verification");
+    }
+
+    Ok(())
+}
+
+/// The answer given on the command line, or the one the day's solution computes.
+fn answer_or_solve(
+    day: u8,
+    part: Part,
+    answer: Option<String>,
+    source: &Source,
+    strictness: Strictness,
+) -> anyhow::Result<String> {
+    let entry = find_day(day)?;
+    match answer {
+        Some(answer) => Ok(answer),
+        None => Ok((entry.solve)(part, &source.read()?, strictness)?.to_string()),
+    }
+}
+
+fn record(
+    day: u8,
+    part: Part,
+    answer: String,
+    name: String,
+    mut answers: Answers,
+) -> anyhow::Result<()> {
+    let key = Key {
+        day,
+        part,
+        input: name.clone(),
+    };
+    match answers.record(key, answer.clone()) {
+        Some(previous) if previous != answer => {
+            println!("Day {day} part {part} ({name}): {previous} -> {answer}")
+        }
+        _ => println!("Day {day} part {part} ({name}): {answer}"),
+    }
+
+    answers.save()
+}
+
+fn fetch(day: Option<u8>, server: ServerArgs) -> anyhow::Result<()> {
+    let days = match day {
+        Some(day) => vec![day],
+        None => registry::DAYS.iter().map(|entry| entry.day).collect(),
+    };
+    let mut fetcher = Fetcher::new(UreqClient::new(), server.base_url, server.session);
+
+    for day in days {
+        if !input::day_dir(day).is_dir() {
+            anyhow::bail!("Day {day} has no solutions/day_{day} crate to fetch into");
+        }
+        let dest = input::default_path(day);
+        match fetcher.fetch(day, &dest)? {
+            Fetched::Cached => println!("Day {day}: cached at {}", dest.display()),
+            Fetched::Downloaded => println!("Day {day}: downloaded to {}", dest.display()),
+        }
+    }
+
+    Ok(())
+}
+
+fn bench(
+    day: Option<u8>,
+    iterations: usize,
+    threshold: f64,
+    baseline_path: PathBuf,
+    save: bool,
+    input_dir: Option<PathBuf>,
+) -> anyhow::Result<()> {
+    let days = match day {
+        Some(day) => vec![find_day(day)?],
+        None => registry::DAYS.iter().collect(),
+    };
+    let mut baseline = Baseline::load(&baseline_path)?;
+
+    let mut table = Table::new([
+        "Day", "Stage", "Min", "Median", "P95", "Baseline", "Change", "Status",
+    ]);
+    let mut regressions = 0;
+    for entry in days {
+        let input = Source::resolve(entry.day, None, input_dir.as_deref()).read()?;
+        let timings = (entry.bench)(&input, iterations)
+            .with_context(|| format!("Day {} failed while benchmarking", entry.day))?;
+
+        for (stage, stats) in timings {
+            let previous = baseline.get(entry.day, stage);
+            let (previous_median, change, status) = match previous {
+                Some(previous) => {
+                    let change = bench::change(previous, stats);
+                    let status = if change > threshold {
+                        regressions += 1;
+                        "REGRESSED"
+                    } else {
+                        "ok"
+                    };
+                    (
+                        bench::format_ns(previous.median_ns),
+                        format!("{:+.1}%", change * 100.0),
+                        status,
+                    )
+                }
+                None => (String::new(), String::new(), "new"),
+            };
+
+            table.row(vec![
+                entry.day.to_string(),
+                stage.to_string(),
+                bench::format_ns(stats.min_ns),
+                bench::format_ns(stats.median_ns),
+                bench::format_ns(stats.p95_ns),
+                previous_median,
+                change,
+                status.into(),
+            ]);
+            if save {
+                baseline.insert(entry.day, stage, stats);
+            }
+        }
+    }
+
+    print!("{table}");
+
+    if save {
+        baseline.save(&baseline_path)?;
+        println!("Saved baseline to {}", baseline_path.display());
+    } else if regressions > 0 {
+        anyhow::bail!("{regressions} stage(s) regressed beyond the threshold");
+    }
+
+    Ok(())
+}
+
+fn new(day: u8) -> anyhow::Result<()> {
+    for path in scaffold::new_day(&input::workspace_root(), day)? {
+        println!("Wrote {}", path.display());
+    }
+
+    Ok(())
+}
+
+fn generate(day: u8, seed: u64, size: usize) -> anyhow::Result<()> {
+    let Some(input) = (find_day(day)?.generate)(seed, size) else {
+        anyhow::bail!("Day {day} has no input generator yet");
+    };
+    print!("{input}");
+    Ok(())
+}
+
+fn watch(
+    day: u8,
+    source: Source,
+    strictness: Strictness,
+    interval: Duration,
+) -> anyhow::Result<()> {
+    let Source::File(input) = source else {
+        anyhow::bail!("Watching needs an input file, not stdin");
+    };
+    let input = input
+        .canonicalize()
+        .with_context(|| format!("Unable to watch {}", input.display()))?;
+    let Ok(dir) = input::day_dir(day).canonicalize() else {
+        anyhow::bail!("Day {day} has no solutions/day_{day} crate to watch");
+    };
+
+    let paths = [dir.join("src"), input.clone()];
+    let mut snapshot = Snapshot::take(&paths);
+    let mut previous = BTreeMap::new();
+    let mut change = Some(Change::Source);
+    loop {
+        if let Some(change) = change {
+            if let Err(e) = rerun(day, &input, strictness, change, &mut previous) {
+                eprintln!("{e:#}");
+            }
+            println!("Watching {} for changes...", dir.display());
+        }
+
+        thread::sleep(interval);
+        let next = Snapshot::take(&paths);
+        change = Change::classify(&next.changed(&snapshot), &input);
+        snapshot = next;
+    }
+}
+
+/// Rebuilds and runs the sample tests if sources changed, then solves the input in a
+/// fresh runner so the latest code is used, printing how each answer moved.
+fn rerun(
+    day: u8,
+    input: &Path,
+    strictness: Strictness,
+    change: Change,
+    previous: &mut BTreeMap<u8, String>,
+) -> anyhow::Result<()> {
+    let root = input::workspace_root();
+    if change == Change::Source {
+        println!("Running day {day}'s sample tests");
+        let status = process::Command::new("cargo")
+            .args(["test", "--quiet", "-p", &format!("day_{day}")])
+            .current_dir(&root)
+            .status()?;
+        if !status.success() {
+            anyhow::bail!("Day {day}'s sample tests failed, not running the real input");
+        }
+    }
+
+    println!("Running day {day} on {}", input.display());
+    let mut runner = process::Command::new("cargo");
+    runner.args(["run", "--release", "--quiet", "-p", "aoc", "--", "run"]);
+    if strictness == Strictness::Strict {
+        runner.arg("--strict");
+    }
+    let output = runner
+        .args(["--day", &day.to_string(), "--format", "json", "--input"])
+        .arg(input)
+        .current_dir(&root)
+        .stderr(Stdio::inherit())
+        .output()?;
+    if !output.status.success() {
+        anyhow::bail!("Day {day} failed on {}", input.display());
+    }
+
+    for line in String::from_utf8(output.stdout)?.lines() {
+        let record: Record = serde_json::from_str(line)
+            .with_context(|| format!("Unexpected output from the runner: {line}"))?;
+        let diff = watch::diff(
+            previous.get(&record.part).map(String::as_str),
+            &record.answer,
+        );
+        println!(
+            "Part {}: {} in {} ({diff})",
+            record.part,
+            record.answer,
+            bench::format_ns(record.elapsed_ns)
+        );
+        previous.insert(record.part, record.answer);
+    }
+
+    Ok(())
+}
+
+fn compare_columns(source: Source) -> anyhow::Result<()> {
+    let columns = day_1::read_columns(source.read()?.as_bytes())?;
+    if columns.len() < 2 {
+        anyhow::bail!("Comparing columns needs at least two of them in {source}");
+    }
+    print!("{}", columns::render(&day_1::ColumnReport::new(&columns)));
+    Ok(())
+}
+
+fn submit(
+    day: u8,
+    part: Part,
+    answer: String,
+    name: String,
+    server: ServerArgs,
+    mut history: History,
+    mut answers: Answers,
+) -> anyhow::Result<()> {
+    let now = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)?
+        .as_secs();
+    let submitter = Submitter::new(UreqClient::new(), server.base_url, server.session);
+    let outcome = submitter.submit(&mut history, day, part, &answer, now)?;
+
+    match outcome.cooldown {
+        Some(cooldown) => println!(
+            "Day {day} part {part}: {answer} is {} (wait {}s)",
+            outcome.verdict,
+            cooldown.as_secs()
+        ),
+        None => println!("Day {day} part {part}: {answer} is {}", outcome.verdict),
+    }
+
+    if outcome.verdict == Verdict::Correct {
+        let key = Key {
+            day,
+            part,
+            input: name,
+        };
+        answers.record(key, answer);
+        answers.save()?;
+    }
+
+    Ok(())
+}
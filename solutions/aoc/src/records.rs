@@ -0,0 +1,27 @@
+use std::path::Path;
+
+use anyhow::Context;
+
+/// Reads a file of one record per line, skipping blank lines and `#` comments, and
+/// parses each record with `parse`. A missing file is treated as having no records yet.
+pub fn load_records<T>(
+    path: &Path,
+    parse: impl Fn(&str) -> anyhow::Result<T>,
+) -> anyhow::Result<Vec<T>> {
+    let contents = match std::fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => {
+            return Err(e).with_context(|| format!("Unable to read {}", path.display()));
+        }
+    };
+
+    contents
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty() && !line.starts_with('#'))
+        .map(|(index, line)| {
+            parse(line).with_context(|| format!("{}:{}: {line:?}", path.display(), index + 1))
+        })
+        .collect()
+}
@@ -0,0 +1,190 @@
+use common::{Answer, Generator, Part, Solution, Strictness};
+
+use crate::bench::{self, Stage, Stats};
+
+pub type Solver = fn(Part, &str, Strictness) -> anyhow::Result<Answer>;
+
+pub type Bencher = fn(&str, usize) -> anyhow::Result<Vec<(Stage, Stats)>>;
+
+/// Produces a random input from a seed and a size.
+pub type Generate = fn(u64, usize) -> Option<String>;
+
+pub struct Day {
+    pub day: u8,
+    pub solve: Solver,
+    pub bench: Bencher,
+    pub generate: Generate,
+}
+
+const fn day<S: Solution + Generator>() -> Day {
+    Day {
+        day: S::DAY,
+        solve: S::solve_with,
+        bench: bench::measure::<S>,
+        generate: S::generate,
+    }
+}
+
+pub const DAYS: &[Day] = &[
+    day::<day_1::Day1>(),
+    day::<day_2::Day2>(),
+    day::<day_3::Day3>(),
+    day::<day_4::Day4>(),
+    day::<day_5::Day5>(),
+    day::<day_6::Day6>(),
+    day::<day_7::Day7>(),
+    day::<day_8::Day8>(),
+    day::<day_9::Day9>(),
+];
+
+pub fn find(day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::{prelude::*, sample::Index};
+
+    use super::*;
+
+    /// Numbers at the edges of the integer types the days parse into.
+    const NUMBERS: &[&str] = &[
+        "0",
+        "1",
+        "-1",
+        "2147483647",
+        "-2147483648",
+        "4294967295",
+        "9223372036854775807",
+        "18446744073709551615",
+        "99999999999999999999",
+    ];
+
+    /// Characters that separate or make up puzzle inputs.
+    const CHARS: &[char] = &[
+        ' ', ',', '|', ':', '-', '0', '9', '#', '.', '^', '<', 'X', 'A', '(',
+    ];
+
+    #[derive(Debug, Clone)]
+    enum Edit {
+        /// Swap the number at or after the position for another.
+        Number(&'static str),
+        /// Swap every number from the position on for the same one, so that totals over
+        /// many lines grow large.
+        Numbers(&'static str),
+        /// Replace the character at the position.
+        Char(char),
+        /// Drop the rest of the line from the position.
+        Truncate,
+    }
+
+    fn edit() -> impl Strategy<Value = (Index, Edit)> {
+        let edit = prop_oneof![
+            prop::sample::select(NUMBERS).prop_map(Edit::Number),
+            prop::sample::select(NUMBERS).prop_map(Edit::Numbers),
+            prop::sample::select(CHARS).prop_map(Edit::Char),
+            Just(Edit::Truncate),
+        ];
+        (any::<Index>(), edit)
+    }
+
+    /// A generated input with a few edits that mostly keep it parseable. Line breaks are
+    /// left alone so that lines, and the work spent on them, stay puzzle sized.
+    fn mutated() -> impl Strategy<Value = (usize, String)> {
+        (
+            0..DAYS.len(),
+            any::<u64>(),
+            1..16_usize,
+            prop::collection::vec(edit(), 1..5),
+        )
+            .prop_filter_map("day has no generator", |(day, seed, size, edits)| {
+                let mut input = (DAYS[day].generate)(seed, size)?;
+                for (at, edit) in edits {
+                    if input.is_empty() {
+                        break;
+                    }
+                    let at = at.index(input.len());
+                    let Some(at) = (at..input.len()).find(|&i| input.is_char_boundary(i)) else {
+                        continue;
+                    };
+                    let rest = &input[at..];
+                    let (range, with) = match edit {
+                        Edit::Number(number) => {
+                            let Some(start) = rest.find(|c: char| c.is_ascii_digit()) else {
+                                continue;
+                            };
+                            let len = rest[start..]
+                                .find(|c: char| !c.is_ascii_digit())
+                                .unwrap_or(rest.len() - start);
+                            (at + start..at + start + len, number.to_owned())
+                        }
+                        Edit::Numbers(number) => {
+                            let mut with = String::new();
+                            for (i, c) in rest.char_indices() {
+                                let prev = rest[..i].chars().next_back();
+                                if !c.is_ascii_digit() {
+                                    with.push(c);
+                                } else if !prev.is_some_and(|p| p.is_ascii_digit()) {
+                                    with.push_str(number);
+                                }
+                            }
+                            (at..input.len(), with)
+                        }
+                        Edit::Char(c) => match rest.chars().next() {
+                            Some(old) if old != '\n' => (at..at + old.len_utf8(), c.to_string()),
+                            _ => continue,
+                        },
+                        Edit::Truncate => (
+                            at..at + rest.find('\n').unwrap_or(rest.len()),
+                            String::new(),
+                        ),
+                    };
+                    input.replace_range(range, &with);
+                }
+                Some((day, input))
+            })
+    }
+
+    fn solve_all(day: usize, input: &str) {
+        for part in Part::ALL {
+            for strictness in [Strictness::Lenient, Strictness::Strict] {
+                let _ = (DAYS[day].solve)(part, input, strictness);
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn arbitrary_input_does_not_panic(day in 0..DAYS.len(), input in "\\PC{0,64}") {
+            solve_all(day, &input);
+        }
+
+        #[test]
+        fn mutated_input_does_not_panic((day, input) in mutated()) {
+            solve_all(day, &input);
+        }
+    }
+
+    #[test]
+    fn generated_inputs_are_solvable() {
+        for entry in DAYS {
+            for (seed, size) in [(0, 1), (1, 12), (2, 50)] {
+                let Some(input) = (entry.generate)(seed, size) else {
+                    // A freshly scaffolded day, without a generator yet.
+                    continue;
+                };
+                assert_eq!(Some(&input), (entry.generate)(seed, size).as_ref());
+                assert!(
+                    !input.trim().is_empty(),
+                    "Day {} seed {seed} size {size} generated an empty input",
+                    entry.day
+                );
+                for part in Part::ALL {
+                    if let Err(e) = (entry.solve)(part, &input, Strictness::Strict) {
+                        panic!("Day {} seed {seed} size {size} part {part}: {e}", entry.day);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use clap::ValueEnum;
+use common::{Answer, Part};
+use serde::{Deserialize, Serialize};
+
+use crate::input::Source;
+
+/// How `run` prints its answers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum Format {
+    /// `Part N: answer` lines
+    #[default]
+    Text,
+    /// One JSON object per line for each solved part
+    Json,
+}
+
+/// One solved part, as printed by `--format json`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    /// Kept as text so large answers survive JSON readers that parse numbers as floats.
+    pub answer: String,
+    /// Name of the input, as used by the answers file.
+    pub input: String,
+    /// Where the input was read from, see [`Source::location`].
+    pub source: String,
+    pub elapsed_ns: u64,
+}
+
+impl Record {
+    pub fn new(day: u8, part: Part, answer: &Answer, source: &Source, elapsed_ns: u64) -> Self {
+        Record {
+            day,
+            part: part.into(),
+            answer: answer.to_string(),
+            input: source.name(),
+            source: source.location(),
+            elapsed_ns,
+        }
+    }
+
+    pub fn render(&self, format: Format) -> anyhow::Result<String> {
+        Ok(match format {
+            Format::Text => format!("Part {}: {}", self.part, self.answer),
+            Format::Json => serde_json::to_string(self)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_text_and_json() {
+        let record = Record::new(7, Part::Two, &Answer::from(11387), &Source::Stdin, 1500);
+        assert_eq!(record.render(Format::Text).unwrap(), "Part 2: 11387");
+        assert_eq!(
+            record.render(Format::Json).unwrap(),
+            r#"{"day":7,"part":2,"answer":"11387","input":"stdin","source":"stdin","elapsed_ns":1500}"#
+        );
+
+        let source = Source::File("sample.txt".into());
+        let record = Record::new(7, Part::One, &Answer::from(3749), &source, 1500);
+        assert_eq!(
+            (record.input.as_str(), record.source.as_str()),
+            ("sample", "sample.txt")
+        );
+    }
+}
@@ -0,0 +1,132 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+
+const TEMPLATES: &[(&str, &str)] = &[
+    ("Cargo.toml", include_str!("../templates/Cargo.toml.tpl")),
+    ("src/main.rs", include_str!("../templates/main.rs.tpl")),
+    ("src/lib.rs", include_str!("../templates/lib.rs.tpl")),
+    ("src/inputs/sample.txt", ""),
+    ("src/inputs/input.txt", ""),
+];
+
+/// Generates `solutions/day_N` under `root` and registers it with the runner, returning
+/// every file written.
+pub fn new_day(root: &Path, day: u8) -> anyhow::Result<Vec<PathBuf>> {
+    if !(1..=25).contains(&day) {
+        anyhow::bail!("There is no day {day}, puzzles run from 1 to 25");
+    }
+
+    let dir = root.join("solutions").join(format!("day_{day}"));
+    if dir.exists() {
+        anyhow::bail!("{} already exists", dir.display());
+    }
+
+    let mut written = vec![];
+    for (name, template) in TEMPLATES {
+        let path = dir.join(name);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Unable to create {}", parent.display()))?;
+        }
+        std::fs::write(&path, template.replace("{{day}}", &day.to_string()))
+            .with_context(|| format!("Unable to write {}", path.display()))?;
+        written.push(path);
+    }
+
+    let runner = root.join("solutions").join("aoc");
+    written.extend(register(
+        &runner.join("Cargo.toml"),
+        "day_",
+        &format!("day_{day} = {{ path = \"../day_{day}\" }}"),
+        day,
+    )?);
+    written.extend(register(
+        &runner.join("src").join("registry.rs"),
+        "    day::<day_",
+        &format!("    day::<day_{day}::Day{day}>(),"),
+        day,
+    )?);
+
+    Ok(written)
+}
+
+/// Inserts `line` into the run of lines starting with `prefix`, keeping the run ordered
+/// by day. Returns the path if it had to be changed.
+fn register(path: &Path, prefix: &str, line: &str, day: u8) -> anyhow::Result<Option<PathBuf>> {
+    let contents = std::fs::read_to_string(path)
+        .with_context(|| format!("Unable to read {}", path.display()))?;
+    let mut lines: Vec<&str> = contents.lines().collect();
+    if lines.contains(&line) {
+        return Ok(None);
+    }
+
+    let registered = |line: &str| {
+        line.strip_prefix(prefix).and_then(|rest| {
+            let digits = rest.find(|c: char| !c.is_ascii_digit())?;
+            rest[..digits].parse::<u8>().ok()
+        })
+    };
+    let last = lines
+        .iter()
+        .rposition(|line| registered(line).is_some())
+        .with_context(|| {
+            format!(
+                "{} has no `{}` entries to extend",
+                path.display(),
+                prefix.trim()
+            )
+        })?;
+    let index = lines
+        .iter()
+        .position(|line| registered(line).is_some_and(|other| other > day))
+        .unwrap_or(last + 1);
+    lines.insert(index, line);
+
+    std::fs::write(path, lines.join("\n") + "\n")
+        .with_context(|| format!("Unable to write {}", path.display()))?;
+
+    Ok(Some(path.to_owned()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::temp::TempPath;
+
+    #[test]
+    fn generates_and_registers_a_day() {
+        let root = TempPath::new("scaffold");
+        let runner = root.join("solutions/aoc");
+        std::fs::create_dir_all(runner.join("src")).unwrap();
+        std::fs::write(
+            runner.join("Cargo.toml"),
+            "[dependencies]\nday_9 = { path = \"../day_9\" }\nday_11 = { path = \"../day_11\" }\n",
+        )
+        .unwrap();
+        std::fs::write(
+            runner.join("src/registry.rs"),
+            "pub const DAYS: &[Day] = &[\n    day::<day_9::Day9>(),\n];\n",
+        )
+        .unwrap();
+
+        let written = new_day(&root, 10).unwrap();
+        assert_eq!(written.len(), TEMPLATES.len() + 2);
+
+        let lib = std::fs::read_to_string(root.join("solutions/day_10/src/lib.rs")).unwrap();
+        assert!(lib.contains("pub struct Day10;"));
+        assert!(lib.contains("const DAY: u8 = 10;"));
+        assert!(root.join("solutions/day_10/src/inputs/sample.txt").exists());
+        assert_eq!(
+            std::fs::read_to_string(runner.join("Cargo.toml")).unwrap(),
+            "[dependencies]\nday_9 = { path = \"../day_9\" }\nday_10 = { path = \"../day_10\" }\nday_11 = { path = \"../day_11\" }\n"
+        );
+        assert_eq!(
+            std::fs::read_to_string(runner.join("src/registry.rs")).unwrap(),
+            "pub const DAYS: &[Day] = &[\n    day::<day_9::Day9>(),\n    day::<day_10::Day10>(),\n];\n"
+        );
+
+        assert!(new_day(&root, 10).is_err());
+        assert!(new_day(&root, 26).is_err());
+    }
+}
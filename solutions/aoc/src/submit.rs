@@ -0,0 +1,370 @@
+use std::{
+    fmt::{Display, Formatter},
+    path::PathBuf,
+    str::FromStr,
+    time::Duration,
+};
+
+use anyhow::Context;
+use common::Part;
+
+use crate::{fetch::YEAR, http::HttpClient, input::workspace_root, records::load_records};
+
+const HEADER: &str = "# day\tpart\tanswer\tverdict\tsubmitted_at\tretry_after";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Incorrect,
+    Wait,
+    AlreadySolved,
+    Unknown,
+}
+
+impl Verdict {
+    pub fn is_wrong(self) -> bool {
+        matches!(
+            self,
+            Verdict::TooHigh | Verdict::TooLow | Verdict::Incorrect
+        )
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too-high",
+            Verdict::TooLow => "too-low",
+            Verdict::Incorrect => "incorrect",
+            Verdict::Wait => "wait",
+            Verdict::AlreadySolved => "already-solved",
+            Verdict::Unknown => "unknown",
+        };
+        write!(f, "{name}")
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "correct" => Verdict::Correct,
+            "too-high" => Verdict::TooHigh,
+            "too-low" => Verdict::TooLow,
+            "incorrect" => Verdict::Incorrect,
+            "wait" => Verdict::Wait,
+            "already-solved" => Verdict::AlreadySolved,
+            "unknown" => Verdict::Unknown,
+            _ => anyhow::bail!("Unknown verdict {s:?}"),
+        })
+    }
+}
+
+/// What the server said about a submission, and how long it wants us to hold off.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Outcome {
+    pub verdict: Verdict,
+    pub cooldown: Option<Duration>,
+}
+
+/// Reads the verdict out of the puzzle server's answer page.
+pub fn parse_response(body: &str) -> Outcome {
+    let text = body.to_lowercase();
+    let verdict = if text.contains("that's the right answer") {
+        Verdict::Correct
+    } else if text.contains("you gave an answer too recently") {
+        Verdict::Wait
+    } else if text.contains("don't seem to be solving the right level") {
+        Verdict::AlreadySolved
+    } else if text.contains("your answer is too high") {
+        Verdict::TooHigh
+    } else if text.contains("your answer is too low") {
+        Verdict::TooLow
+    } else if text.contains("that's not the right answer") {
+        Verdict::Incorrect
+    } else {
+        Verdict::Unknown
+    };
+
+    Outcome {
+        verdict,
+        cooldown: parse_cooldown(&text),
+    }
+}
+
+/// Handles both "you have 1m 30s left to wait" and "please wait 5 minutes before trying again".
+fn parse_cooldown(text: &str) -> Option<Duration> {
+    if let Some(end) = text.find(" left to wait") {
+        let start = text[..end].rfind("you have ")? + "you have ".len();
+        let seconds = text[start..end]
+            .split_whitespace()
+            .map(|token| {
+                let (value, unit) = token.split_at(token.len().checked_sub(1)?);
+                let value: u64 = value.parse().ok()?;
+                match unit {
+                    "h" => Some(value * 3600),
+                    "m" => Some(value * 60),
+                    "s" => Some(value),
+                    _ => None,
+                }
+            })
+            .sum::<Option<u64>>()?;
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let start = text.find("please wait ")? + "please wait ".len();
+    let mut words = text[start..].split_whitespace();
+    let minutes = match words.next()? {
+        "one" => 1,
+        count => count.parse().ok()?,
+    };
+    words
+        .next()
+        .filter(|unit| unit.starts_with("minute"))
+        .map(|_| Duration::from_secs(minutes * 60))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Guess {
+    pub day: u8,
+    pub part: Part,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// Unix seconds the guess was submitted at.
+    pub submitted_at: u64,
+    /// Unix seconds before which the server will not accept another guess.
+    pub retry_after: u64,
+}
+
+/// Every answer submitted so far, stored one per line as tab separated fields.
+#[derive(Debug)]
+pub struct History {
+    path: PathBuf,
+    guesses: Vec<Guess>,
+}
+
+impl History {
+    pub fn default_path() -> PathBuf {
+        workspace_root().join("submissions.txt")
+    }
+
+    /// Loads the history file, a missing file is treated as no submissions yet.
+    pub fn load(path: impl Into<PathBuf>) -> anyhow::Result<Self> {
+        let path = path.into();
+        let guesses = load_records(&path, parse_guess)?;
+
+        Ok(History { path, guesses })
+    }
+
+    pub fn save(&self) -> anyhow::Result<()> {
+        let mut contents = format!("{HEADER}\n");
+        for guess in &self.guesses {
+            contents.push_str(&format!(
+                "{}\t{}\t{}\t{}\t{}\t{}\n",
+                guess.day,
+                guess.part,
+                guess.answer,
+                guess.verdict,
+                guess.submitted_at,
+                guess.retry_after
+            ));
+        }
+
+        std::fs::write(&self.path, contents)
+            .with_context(|| format!("Unable to write {}", self.path.display()))
+    }
+
+    /// Refuses guesses the server has already ruled on, or that it would reject for
+    /// arriving before the cooldown is over.
+    pub fn check(&self, day: u8, part: Part, answer: &str, now: u64) -> anyhow::Result<()> {
+        let guesses = || {
+            self.guesses
+                .iter()
+                .filter(move |guess| guess.day == day && guess.part == part)
+        };
+
+        if let Some(guess) = guesses().find(|guess| guess.verdict == Verdict::Correct) {
+            anyhow::bail!(
+                "Day {day} part {part} is already solved with {}",
+                guess.answer
+            );
+        }
+        if let Some(guess) =
+            guesses().find(|guess| guess.answer == answer && guess.verdict.is_wrong())
+        {
+            anyhow::bail!("{answer} was already rejected as {}", guess.verdict);
+        }
+        if let Ok(value) = answer.parse::<i128>() {
+            let bound = guesses().find(|guess| {
+                guess.answer.parse::<i128>().is_ok_and(|guessed| {
+                    (guess.verdict == Verdict::TooHigh && value >= guessed)
+                        || (guess.verdict == Verdict::TooLow && value <= guessed)
+                })
+            });
+            if let Some(guess) = bound {
+                anyhow::bail!(
+                    "{answer} is ruled out by {} being {}",
+                    guess.answer,
+                    guess.verdict
+                );
+            }
+        }
+
+        let retry_after = self
+            .guesses
+            .iter()
+            .map(|guess| guess.retry_after)
+            .max()
+            .unwrap_or_default();
+        if retry_after > now {
+            anyhow::bail!(
+                "Still cooling down, wait {}s before submitting",
+                retry_after - now
+            );
+        }
+
+        Ok(())
+    }
+
+    pub fn push(&mut self, guess: Guess) {
+        self.guesses.push(guess);
+    }
+}
+
+fn parse_guess(line: &str) -> anyhow::Result<Guess> {
+    let fields: Vec<&str> = line.split('\t').collect();
+    let [day, part, answer, verdict, submitted_at, retry_after] = fields[..] else {
+        anyhow::bail!("Expected 6 tab separated fields");
+    };
+
+    Ok(Guess {
+        day: day.parse().context("Invalid day")?,
+        part: Part::try_from(part.parse::<u8>().context("Invalid part")?)?,
+        answer: answer.to_owned(),
+        verdict: verdict.parse()?,
+        submitted_at: submitted_at.parse().context("Invalid submission time")?,
+        retry_after: retry_after.parse().context("Invalid retry time")?,
+    })
+}
+
+pub struct Submitter<C> {
+    client: C,
+    base_url: String,
+    session: String,
+}
+
+impl<C: HttpClient> Submitter<C> {
+    pub fn new(client: C, base_url: impl Into<String>, session: impl Into<String>) -> Self {
+        Submitter {
+            client,
+            base_url: base_url.into().trim_end_matches('/').to_owned(),
+            session: session.into(),
+        }
+    }
+
+    /// Submits `answer` unless `history` already rules it out, then records the outcome.
+    pub fn submit(
+        &self,
+        history: &mut History,
+        day: u8,
+        part: Part,
+        answer: &str,
+        now: u64,
+    ) -> anyhow::Result<Outcome> {
+        history.check(day, part, answer, now)?;
+
+        let url = format!("{}/{YEAR}/day/{day}/answer", self.base_url);
+        let level = part.to_string();
+        let response = self
+            .client
+            .post(
+                &url,
+                &self.session,
+                &[("level", &level), ("answer", answer)],
+            )
+            .with_context(|| format!("Unable to reach {url}"))?;
+        if response.status != 200 {
+            anyhow::bail!("Unexpected {} from {url}", response.status);
+        }
+
+        let outcome = parse_response(&response.body);
+        history.push(Guess {
+            day,
+            part,
+            answer: answer.to_owned(),
+            verdict: outcome.verdict,
+            submitted_at: now,
+            retry_after: now + outcome.cooldown.unwrap_or_default().as_secs(),
+        });
+        history.save()?;
+
+        Ok(outcome)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        http::{UreqClient, mock::MockServer},
+        temp::TempPath,
+    };
+
+    const TOO_HIGH: &str = "<article><p>That's not the right answer; your answer is too high. \
+        Please wait one minute before trying again.</p></article>";
+
+    #[test]
+    fn parses_verdicts_and_cooldowns() {
+        assert_eq!(
+            parse_response(TOO_HIGH),
+            Outcome {
+                verdict: Verdict::TooHigh,
+                cooldown: Some(Duration::from_secs(60)),
+            }
+        );
+        assert_eq!(
+            parse_response("You gave an answer too recently. You have 1m 30s left to wait."),
+            Outcome {
+                verdict: Verdict::Wait,
+                cooldown: Some(Duration::from_secs(90)),
+            }
+        );
+        assert_eq!(
+            parse_response("That's the right answer! You are one gold star closer.").verdict,
+            Verdict::Correct
+        );
+    }
+
+    #[test]
+    fn records_guesses_and_refuses_known_wrong_answers() {
+        let server = MockServer::start(|_| (200, TOO_HIGH.into()));
+        let submitter = Submitter::new(UreqClient::new(), &server.url, "secret");
+        let path = TempPath::new("submit-wrong");
+        let mut history = History::load(&*path).unwrap();
+
+        let outcome = submitter
+            .submit(&mut history, 6, Part::Two, "1700", 1_000)
+            .unwrap();
+        assert_eq!(outcome.verdict, Verdict::TooHigh);
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].path, "/2024/day/6/answer");
+        assert_eq!(requests[0].body, "level=2&answer=1700");
+
+        let cooling = submitter.submit(&mut history, 6, Part::Two, "1670", 1_030);
+        assert!(cooling.unwrap_err().to_string().contains("wait 30s"));
+        let repeat = submitter.submit(&mut history, 6, Part::Two, "1700", 2_000);
+        assert!(repeat.unwrap_err().to_string().contains("already rejected"));
+        let higher = submitter.submit(&mut history, 6, Part::Two, "1800", 2_000);
+        assert!(higher.unwrap_err().to_string().contains("ruled out"));
+        assert_eq!(server.requests().len(), 1);
+
+        let reloaded = History::load(&*path).unwrap();
+        assert_eq!(reloaded.guesses, history.guesses);
+    }
+}
@@ -0,0 +1,77 @@
+use std::fmt::{Display, Formatter};
+
+/// A plain-text table with left-aligned columns sized to their widest cell.
+pub struct Table {
+    headers: Vec<String>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub fn new(headers: impl IntoIterator<Item = impl ToString>) -> Self {
+        Table {
+            headers: headers.into_iter().map(|h| h.to_string()).collect(),
+            rows: vec![],
+        }
+    }
+
+    pub fn row(&mut self, row: Vec<String>) {
+        self.rows.push(row);
+    }
+
+    fn widths(&self) -> Vec<usize> {
+        self.headers
+            .iter()
+            .enumerate()
+            .map(|(col, header)| {
+                self.rows
+                    .iter()
+                    .filter_map(|row| row.get(col))
+                    .map(|cell| cell.chars().count())
+                    .chain([header.chars().count()])
+                    .max()
+                    .unwrap_or_default()
+            })
+            .collect()
+    }
+}
+
+fn write_row(f: &mut Formatter<'_>, row: &[String], widths: &[usize]) -> std::fmt::Result {
+    let line = row
+        .iter()
+        .zip(widths)
+        .map(|(cell, width)| format!("{cell:<width$}"))
+        .collect::<Vec<_>>()
+        .join("  ");
+    writeln!(f, "{}", line.trim_end())
+}
+
+impl Display for Table {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let widths = self.widths();
+        write_row(f, &self.headers, &widths)?;
+        let rule: Vec<String> = widths.iter().map(|w| "-".repeat(*w)).collect();
+        write_row(f, &rule, &widths)?;
+        for row in &self.rows {
+            write_row(f, row, &widths)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn columns_are_padded_to_widest_cell() {
+        let mut table = Table::new(["Day", "Answer"]);
+        table.row(vec!["1".into(), "1834060".into()]);
+        table.row(vec!["10".into(), "7".into()]);
+
+        assert_eq!(
+            table.to_string(),
+            "Day  Answer\n---  -------\n1    1834060\n10   7\n"
+        );
+    }
+}
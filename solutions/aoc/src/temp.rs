@@ -0,0 +1,39 @@
+use std::{
+    ops::Deref,
+    path::{Path, PathBuf},
+};
+
+/// A file or directory path in the system temp directory, unique to this test run, that
+/// is removed along with everything under it when dropped.
+pub struct TempPath(PathBuf);
+
+impl TempPath {
+    /// The path starts out missing, even if an earlier run that panicked left it behind.
+    pub fn new(name: &str) -> Self {
+        let path = std::env::temp_dir().join(format!("aoc-{name}-{}", std::process::id()));
+        remove(&path);
+        TempPath(path)
+    }
+}
+
+impl Deref for TempPath {
+    type Target = Path;
+
+    fn deref(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for TempPath {
+    fn drop(&mut self) {
+        remove(&self.0);
+    }
+}
+
+fn remove(path: &Path) {
+    let _ = if path.is_dir() {
+        std::fs::remove_dir_all(path)
+    } else {
+        std::fs::remove_file(path)
+    };
+}
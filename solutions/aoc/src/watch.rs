@@ -0,0 +1,154 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+/// Modification times of every file under a set of paths, compared between polls to spot
+/// edits.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Snapshot(BTreeMap<PathBuf, SystemTime>);
+
+impl Snapshot {
+    /// Walks `paths`, each a file or a directory, skipping any that do not exist.
+    pub fn take(paths: &[PathBuf]) -> Self {
+        let mut snapshot = Snapshot::default();
+        for path in paths {
+            snapshot.walk(path);
+        }
+        snapshot
+    }
+
+    fn walk(&mut self, path: &Path) {
+        let Ok(metadata) = fs::metadata(path) else {
+            return;
+        };
+        if metadata.is_dir() {
+            let Ok(entries) = fs::read_dir(path) else {
+                return;
+            };
+            for entry in entries.flatten() {
+                self.walk(&entry.path());
+            }
+        } else if let Ok(modified) = metadata.modified() {
+            self.0.insert(path.to_owned(), modified);
+        }
+    }
+
+    /// Files added, removed or modified since `earlier`.
+    pub fn changed(&self, earlier: &Snapshot) -> Vec<PathBuf> {
+        let modified = self
+            .0
+            .iter()
+            .filter(|(path, time)| earlier.0.get(*path) != Some(time))
+            .map(|(path, _)| path.clone());
+        let removed = earlier
+            .0
+            .keys()
+            .filter(|path| !self.0.contains_key(*path))
+            .cloned();
+        modified.chain(removed).collect()
+    }
+}
+
+/// What a batch of changed files calls for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Change {
+    /// Code or sample edits: rebuild, run the sample tests, then the real input.
+    Source,
+    /// Only the real input changed, which is read at runtime, so just run it again.
+    Input,
+}
+
+impl Change {
+    /// Paths are compared once canonicalized, so `input` may be spelled differently from
+    /// the walked copy of the same file.
+    pub fn classify(changed: &[PathBuf], input: &Path) -> Option<Change> {
+        let input = canonical(input);
+        match changed {
+            [] => None,
+            _ if changed.iter().all(|path| canonical(path) == input) => Some(Change::Input),
+            _ => Some(Change::Source),
+        }
+    }
+}
+
+/// `path` with links and `..` resolved, or as given once it no longer exists.
+fn canonical(path: &Path) -> PathBuf {
+    path.canonicalize().unwrap_or_else(|_| path.to_owned())
+}
+
+/// How an answer compares with the one from the previous run.
+pub fn diff(previous: Option<&str>, current: &str) -> String {
+    match previous {
+        None => "new".into(),
+        Some(previous) if previous == current => "unchanged".into(),
+        Some(previous) => format!("was {previous}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::temp::TempPath;
+
+    #[test]
+    fn spots_changed_files() {
+        let dir = TempPath::new("watch");
+        fs::create_dir_all(dir.join("src")).unwrap();
+        let lib = dir.join("src/lib.rs");
+        let input = dir.join("input.txt");
+        fs::write(&lib, "").unwrap();
+        fs::write(&input, "1").unwrap();
+
+        let paths = [dir.join("src"), input.clone()];
+        let before = Snapshot::take(&paths);
+        assert!(Snapshot::take(&paths).changed(&before).is_empty());
+
+        let later = SystemTime::now() + std::time::Duration::from_secs(5);
+        fs::File::options()
+            .write(true)
+            .open(&input)
+            .unwrap()
+            .set_modified(later)
+            .unwrap();
+        let after = Snapshot::take(&paths);
+        let modified = after.changed(&before);
+        assert_eq!(modified, [input.as_path()]);
+        assert_eq!(Change::classify(&modified, &input), Some(Change::Input));
+
+        fs::remove_file(&lib).unwrap();
+        let removed = Snapshot::take(&paths).changed(&after);
+        assert_eq!(removed, [lib]);
+        assert_eq!(Change::classify(&removed, &input), Some(Change::Source));
+    }
+
+    #[test]
+    fn input_under_watched_sources_is_still_input() {
+        let dir = TempPath::new("watch-dotdot");
+        fs::create_dir_all(dir.join("src/inputs")).unwrap();
+        let input = dir.join("src/inputs/input.txt");
+        fs::write(&input, "1").unwrap();
+
+        let spelled = dir.join("src/../src/inputs/input.txt");
+        let paths = [dir.canonicalize().unwrap().join("src"), spelled.clone()];
+        let before = Snapshot::take(&paths);
+        let later = SystemTime::now() + std::time::Duration::from_secs(5);
+        fs::File::options()
+            .write(true)
+            .open(&input)
+            .unwrap()
+            .set_modified(later)
+            .unwrap();
+        let changed = Snapshot::take(&paths).changed(&before);
+        assert_eq!(Change::classify(&changed, &spelled), Some(Change::Input));
+    }
+
+    #[test]
+    fn describes_answer_changes() {
+        assert_eq!(diff(None, "42"), "new");
+        assert_eq!(diff(Some("42"), "42"), "unchanged");
+        assert_eq!(diff(Some("41"), "42"), "was 41");
+    }
+}
@@ -0,0 +1,8 @@
+[package]
+name = "day_{{day}}"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+anyhow.workspace = true
+common.workspace = true
@@ -0,0 +1,50 @@
+use common::{Answer, Generator, Solution};
+
+pub struct Day{{day}};
+
+impl Solution for Day{{day}} {
+    type Input = Vec<String>;
+
+    const DAY: u8 = {{day}};
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    fn part_1(_input: &Self::Input) -> anyhow::Result<Answer> {
+        anyhow::bail!("Day {{day}} part 1 is not solved yet")
+    }
+
+    fn part_2(_input: &Self::Input) -> anyhow::Result<Answer> {
+        anyhow::bail!("Day {{day}} part 2 is not solved yet")
+    }
+}
+
+impl Generator for Day{{day}} {
+    fn generate(_seed: u64, _size: usize) -> Option<String> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Part;
+
+    use super::*;
+
+    #[test]
+    fn part_1_test() {
+        let input = include_str!("inputs/sample.txt");
+        // Swap for `assert_eq!(answer.unwrap(), <sample answer>.into())` once solved.
+        let answer = Day{{day}}::solve(Part::One, input);
+        assert!(answer.is_err());
+    }
+
+    #[test]
+    fn part_2_test() {
+        let input = include_str!("inputs/sample.txt");
+        // Swap for `assert_eq!(answer.unwrap(), <sample answer>.into())` once solved.
+        let answer = Day{{day}}::solve(Part::Two, input);
+        assert!(answer.is_err());
+    }
+}
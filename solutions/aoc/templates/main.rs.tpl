@@ -0,0 +1,17 @@
+use anyhow::Context;
+use common::{Solution, Strictness};
+use day_{{day}}::Day{{day}};
+
+fn main() -> anyhow::Result<()> {
+    let path = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| concat!(env!("CARGO_MANIFEST_DIR"), "/src/inputs/input.txt").into());
+    let input = std::fs::read_to_string(&path)
+        .with_context(|| format!("Unable to read input from {path}"))?;
+    let parsed = Day{{day}}::load(&input, Strictness::Lenient)?;
+
+    println!("Part 1: {}", Day{{day}}::part_1(&parsed)?);
+    println!("Part 2: {}", Day{{day}}::part_2(&parsed)?);
+
+    Ok(())
+}
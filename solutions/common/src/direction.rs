@@ -0,0 +1,219 @@
+use std::fmt::{Display, Formatter};
+
+use glam::IVec2;
+
+/// One of the four orthogonal directions, with up being towards row 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Clockwise, starting from up.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    fn from_index(index: usize) -> Self {
+        Direction::ALL[index % Direction::ALL.len()]
+    }
+
+    pub fn clockwise(self) -> Self {
+        Direction::from_index(self as usize + 1)
+    }
+
+    pub fn counter_clockwise(self) -> Self {
+        Direction::from_index(self as usize + 3)
+    }
+
+    pub fn reverse(self) -> Self {
+        Direction::from_index(self as usize + 2)
+    }
+
+    /// The offset of one step in this direction.
+    pub fn offset(self) -> IVec2 {
+        Compass::from(self).offset()
+    }
+
+    /// Parses `^`, `>`, `v` or `<`.
+    pub fn from_arrow(c: char) -> Option<Self> {
+        match c {
+            '^' => Some(Direction::Up),
+            '>' => Some(Direction::Right),
+            'v' => Some(Direction::Down),
+            '<' => Some(Direction::Left),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Direction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Direction::Up => write!(f, "^"),
+            Direction::Right => write!(f, ">"),
+            Direction::Down => write!(f, "v"),
+            Direction::Left => write!(f, "<"),
+        }
+    }
+}
+
+impl From<Direction> for IVec2 {
+    fn from(direction: Direction) -> Self {
+        direction.offset()
+    }
+}
+
+impl TryFrom<IVec2> for Direction {
+    type Error = anyhow::Error;
+
+    fn try_from(offset: IVec2) -> Result<Self, Self::Error> {
+        Direction::ALL
+            .into_iter()
+            .find(|direction| direction.offset() == offset)
+            .ok_or_else(|| anyhow::anyhow!("{offset} is not an orthogonal unit step"))
+    }
+}
+
+/// One of the eight compass directions, with north being towards row 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Compass {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Compass {
+    /// Clockwise, starting from north.
+    pub const ALL: [Compass; 8] = [
+        Compass::N,
+        Compass::NE,
+        Compass::E,
+        Compass::SE,
+        Compass::S,
+        Compass::SW,
+        Compass::W,
+        Compass::NW,
+    ];
+
+    fn from_index(index: usize) -> Self {
+        Compass::ALL[index % Compass::ALL.len()]
+    }
+
+    /// Rotates 45 degrees clockwise.
+    pub fn clockwise(self) -> Self {
+        Compass::from_index(self as usize + 1)
+    }
+
+    /// Rotates 45 degrees counter-clockwise.
+    pub fn counter_clockwise(self) -> Self {
+        Compass::from_index(self as usize + 7)
+    }
+
+    pub fn reverse(self) -> Self {
+        Compass::from_index(self as usize + 4)
+    }
+
+    /// The offset of one step in this direction.
+    pub fn offset(self) -> IVec2 {
+        match self {
+            Compass::N => IVec2::NEG_Y,
+            Compass::NE => IVec2::new(1, -1),
+            Compass::E => IVec2::X,
+            Compass::SE => IVec2::ONE,
+            Compass::S => IVec2::Y,
+            Compass::SW => IVec2::new(-1, 1),
+            Compass::W => IVec2::NEG_X,
+            Compass::NW => IVec2::NEG_ONE,
+        }
+    }
+
+    /// Parses one of the arrows `↑`, `↗`, `→`, `↘`, `↓`, `↙`, `←` or `↖`.
+    pub fn from_arrow(c: char) -> Option<Self> {
+        "↑↗→↘↓↙←↖"
+            .chars()
+            .position(|arrow| arrow == c)
+            .map(Compass::from_index)
+    }
+}
+
+impl Display for Compass {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let arrow = "↑↗→↘↓↙←↖".chars().nth(*self as usize).unwrap();
+        write!(f, "{arrow}")
+    }
+}
+
+impl From<Direction> for Compass {
+    fn from(direction: Direction) -> Self {
+        Compass::from_index(direction as usize * 2)
+    }
+}
+
+impl From<Compass> for IVec2 {
+    fn from(compass: Compass) -> Self {
+        compass.offset()
+    }
+}
+
+impl TryFrom<IVec2> for Compass {
+    type Error = anyhow::Error;
+
+    fn try_from(offset: IVec2) -> Result<Self, Self::Error> {
+        Compass::ALL
+            .into_iter()
+            .find(|compass| compass.offset() == offset)
+            .ok_or_else(|| anyhow::anyhow!("{offset} is not a unit step"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rotates_and_reverses() {
+        assert_eq!(Direction::Left.clockwise(), Direction::Up);
+        assert_eq!(Direction::Up.counter_clockwise(), Direction::Left);
+        assert_eq!(Direction::Right.reverse(), Direction::Left);
+        assert_eq!(Compass::NW.clockwise(), Compass::N);
+        assert_eq!(Compass::N.counter_clockwise(), Compass::NW);
+        assert_eq!(Compass::NE.reverse(), Compass::SW);
+    }
+
+    #[test]
+    fn converts_offsets_and_arrows() {
+        for direction in Direction::ALL {
+            assert_eq!(Direction::try_from(direction.offset()).unwrap(), direction);
+            assert_eq!(
+                Direction::from_arrow(direction.to_string().chars().next().unwrap()),
+                Some(direction)
+            );
+            assert_eq!(
+                direction.offset() + direction.reverse().offset(),
+                IVec2::ZERO
+            );
+        }
+        for compass in Compass::ALL {
+            assert_eq!(Compass::try_from(compass.offset()).unwrap(), compass);
+            assert_eq!(
+                Compass::from_arrow(compass.to_string().chars().next().unwrap()),
+                Some(compass)
+            );
+        }
+        assert_eq!(Compass::from(Direction::Left), Compass::W);
+        assert!(Direction::try_from(IVec2::ONE).is_err());
+        assert_eq!(Direction::from_arrow('x'), None);
+    }
+}
@@ -0,0 +1,7 @@
+/// Produces random puzzle inputs in exactly the text format the day's parser accepts.
+pub trait Generator {
+    /// The same `seed` and `size` always produce the same input. `size` scales it, such
+    /// as the number of lines or the side of a grid. Days without a generator yet return
+    /// `None`.
+    fn generate(seed: u64, size: usize) -> Option<String>;
+}
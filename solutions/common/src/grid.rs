@@ -0,0 +1,264 @@
+use std::{
+    fmt::{Display, Formatter},
+    ops::{Index, IndexMut},
+};
+
+use glam::IVec2;
+
+use crate::{Compass, Direction, ParseError};
+
+/// A rectangular grid of cells addressed by `IVec2 { x: column, y: row }`, with `(0, 0)`
+/// in the top left corner.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from its cells in row-major order.
+    pub fn new(width: usize, cells: Vec<T>) -> anyhow::Result<Self> {
+        if width == 0 || !cells.len().is_multiple_of(width) {
+            anyhow::bail!("{} cells do not fill rows of width {width}", cells.len());
+        }
+
+        Ok(Grid {
+            width,
+            height: cells.len() / width,
+            cells,
+        })
+    }
+
+    /// Parses one row per line, mapping every character to a cell with `cell`, which
+    /// returns `None` for characters that are not `expected`.
+    pub fn parse(
+        day: u8,
+        input: &str,
+        expected: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut cells = vec![];
+        for line in input.lines() {
+            let start = cells.len();
+            for (i, c) in line.char_indices() {
+                match cell(c) {
+                    Some(cell) => cells.push(cell),
+                    None => return Err(ParseError::at(day, input, &line[i..], expected)),
+                }
+            }
+
+            let len = cells.len() - start;
+            match width {
+                None => width = Some(len),
+                Some(width) if width != len => {
+                    let end = &line[line.len()..];
+                    return Err(ParseError::at(
+                        day,
+                        input,
+                        end,
+                        format!("a row {width} cells wide"),
+                    ));
+                }
+                Some(_) => {}
+            }
+        }
+
+        match width {
+            Some(width) if width > 0 => Ok(Grid {
+                width,
+                height: cells.len() / width,
+                cells,
+            }),
+            _ => Err(ParseError::new(day, input, 0, "at least one row of cells")),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos: IVec2) -> bool {
+        (0..self.width as i32).contains(&pos.x) && (0..self.height as i32).contains(&pos.y)
+    }
+
+    fn offset(&self, pos: IVec2) -> Option<usize> {
+        self.contains(pos)
+            .then(|| pos.y as usize * self.width + pos.x as usize)
+    }
+
+    pub fn get(&self, pos: IVec2) -> Option<&T> {
+        self.offset(pos).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, pos: IVec2) -> Option<&mut T> {
+        self.offset(pos).map(|i| &mut self.cells[i])
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = IVec2> {
+        let width = self.width as i32;
+        (0..self.cells.len() as i32).map(move |i| IVec2::new(i % width, i / width))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (IVec2, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// Position of the first cell, row by row, matching `predicate`.
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<IVec2> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(pos, _)| pos)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width as i32).map(|x| {
+            self.ray(IVec2::new(x, 0), Direction::Down.offset())
+                .map(|(_, cell)| cell)
+        })
+    }
+
+    /// Every diagonal running down and to the right, starting from the bottom left corner.
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let starts = (0..self.height as i32)
+            .rev()
+            .map(|y| IVec2::new(0, y))
+            .chain((1..self.width as i32).map(|x| IVec2::new(x, 0)));
+        starts.map(|start| self.ray(start, Compass::SE.offset()).map(|(_, cell)| cell))
+    }
+
+    /// Every diagonal running down and to the left, starting from the top left corner.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let last = self.width as i32 - 1;
+        let starts = (0..self.width as i32)
+            .map(|x| IVec2::new(x, 0))
+            .chain((1..self.height as i32).map(move |y| IVec2::new(last, y)));
+        starts.map(|start| self.ray(start, Compass::SW.offset()).map(|(_, cell)| cell))
+    }
+
+    /// Cells from `start` onwards in steps of `step`, until the edge of the grid.
+    pub fn ray(&self, start: IVec2, step: IVec2) -> impl Iterator<Item = (IVec2, &T)> {
+        std::iter::successors(Some(start), move |pos| Some(pos + step))
+            .map_while(|pos| self.get(pos).map(|cell| (pos, cell)))
+    }
+
+    /// The up to four cells sharing an edge with `pos`.
+    pub fn neighbours(&self, pos: IVec2) -> impl Iterator<Item = (IVec2, &T)> {
+        self.around(pos, Direction::ALL.map(Direction::offset))
+    }
+
+    /// The up to eight cells sharing an edge or a corner with `pos`.
+    pub fn neighbours_diagonal(&self, pos: IVec2) -> impl Iterator<Item = (IVec2, &T)> {
+        self.around(pos, Compass::ALL.map(Compass::offset))
+    }
+
+    fn around<const N: usize>(
+        &self,
+        pos: IVec2,
+        offsets: [IVec2; N],
+    ) -> impl Iterator<Item = (IVec2, &T)> {
+        offsets
+            .into_iter()
+            .filter_map(move |offset| self.get(pos + offset).map(|cell| (pos + offset, cell)))
+    }
+}
+
+impl<T> Index<IVec2> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: IVec2) -> &Self::Output {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{pos} is outside a {}x{} grid", self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<IVec2> for Grid<T> {
+    fn index_mut(&mut self, pos: IVec2) -> &mut Self::Output {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{pos} is outside a {width}x{height} grid"))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        Grid::parse(0, "abc\ndef", "a letter", Some).unwrap()
+    }
+
+    fn collect<'a>(lines: impl Iterator<Item = impl Iterator<Item = &'a char>>) -> Vec<String> {
+        lines.map(|line| line.collect()).collect()
+    }
+
+    #[test]
+    fn indexes_by_signed_coordinates() {
+        let grid = grid();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[IVec2::new(2, 1)], 'f');
+        assert_eq!(grid.get(IVec2::new(-1, 0)), None);
+        assert_eq!(grid.get(IVec2::new(3, 0)), None);
+        assert_eq!(grid.position(|&c| c == 'e'), Some(IVec2::new(1, 1)));
+        assert_eq!(grid.to_string(), "abc\ndef\n");
+    }
+
+    #[test]
+    fn walks_rows_columns_and_diagonals() {
+        let grid = grid();
+        assert_eq!(grid.rows().map(|row| row.len()).collect::<Vec<_>>(), [3, 3]);
+        assert_eq!(collect(grid.columns()), ["ad", "be", "cf"]);
+        assert_eq!(collect(grid.diagonals()), ["d", "ae", "bf", "c"]);
+        assert_eq!(collect(grid.anti_diagonals()), ["a", "bd", "ce", "f"]);
+        assert_eq!(
+            grid.neighbours(IVec2::ZERO)
+                .map(|(_, c)| *c)
+                .collect::<String>(),
+            "bd"
+        );
+        assert_eq!(
+            grid.neighbours_diagonal(IVec2::new(1, 0))
+                .map(|(_, c)| *c)
+                .collect::<String>(),
+            "cfeda"
+        );
+    }
+
+    #[test]
+    fn rejects_ragged_and_invalid_input() {
+        let ragged = Grid::parse(0, "ab\nc", "a letter", Some).unwrap_err();
+        assert_eq!((ragged.line, ragged.column), (2, 2));
+        assert!(Grid::parse(0, "", "a letter", Some).is_err());
+
+        let invalid = Grid::parse(0, "ab\n?b", "a or b", |c| {
+            matches!(c, 'a' | 'b').then_some(c)
+        })
+        .unwrap_err();
+        assert_eq!((invalid.line, invalid.column), (2, 1));
+        assert_eq!(invalid.expected, "a or b");
+    }
+}
@@ -0,0 +1,16 @@
+mod answer;
+mod direction;
+mod generator;
+mod grid;
+mod normalize;
+pub mod parse;
+mod solution;
+
+pub use answer::Answer;
+pub use direction::{Compass, Direction};
+pub use generator::Generator;
+pub use glam::IVec2;
+pub use grid::Grid;
+pub use normalize::{Strictness, normalize};
+pub use parse::ParseError;
+pub use solution::{Part, Solution};
@@ -0,0 +1,66 @@
+use std::borrow::Cow;
+
+use crate::ParseError;
+
+/// How forgiving [`normalize`] is about blank lines after the last line of input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Strictness {
+    /// Drop them.
+    #[default]
+    Lenient,
+    /// Report them, allowing only a single final newline.
+    Strict,
+}
+
+/// Strips a byte order mark, turns `\r\n` and lone `\r` line endings into `\n`, and
+/// removes the final newline along with any blank lines after it.
+pub fn normalize(day: u8, input: &str, strictness: Strictness) -> Result<Cow<'_, str>, ParseError> {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+    let text = if input.contains('\r') {
+        Cow::Owned(input.replace("\r\n", "\n").replace('\r', "\n"))
+    } else {
+        Cow::Borrowed(input)
+    };
+
+    let content = text.trim_end().len();
+    let end = text[content..]
+        .find('\n')
+        .map_or(text.len(), |i| content + i);
+    if strictness == Strictness::Strict && !matches!(&text[end..], "" | "\n") {
+        return Err(ParseError::new(day, &text, end + 1, "end of input"));
+    }
+
+    Ok(match text {
+        Cow::Borrowed(text) => Cow::Borrowed(&text[..end]),
+        Cow::Owned(mut text) => {
+            text.truncate(end);
+            Cow::Owned(text)
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lenient(input: &str) -> Cow<'_, str> {
+        normalize(0, input, Strictness::Lenient).unwrap()
+    }
+
+    #[test]
+    fn normalizes_encoding_and_trailing_lines() {
+        assert_eq!(lenient("\u{feff}1 2\r\n3 4\r\n"), "1 2\n3 4");
+        assert_eq!(lenient("1 2\r3 4"), "1 2\n3 4");
+        assert_eq!(lenient("ab  \n\n  \n\n"), "ab  ");
+        assert_eq!(lenient("\n\n"), "");
+        assert!(matches!(lenient("1 2\n3 4\n"), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn strict_mode_reports_trailing_blank_lines() {
+        assert_eq!(normalize(0, "1 2\r\n", Strictness::Strict).unwrap(), "1 2");
+        let error = normalize(9, "12345\n\n\n", Strictness::Strict).unwrap_err();
+        assert_eq!((error.day, error.line, error.column), (9, 2, 1));
+        assert_eq!(error.expected, "end of input");
+    }
+}
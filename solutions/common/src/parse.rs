@@ -0,0 +1,178 @@
+use std::fmt::{Display, Formatter};
+
+use nom::IResult;
+
+/// A parse failure pinned to a line and column of a day's puzzle input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+    /// The whole offending line.
+    pub snippet: String,
+    pub expected: String,
+}
+
+impl ParseError {
+    /// Points at byte `offset` of `input`.
+    pub fn new(day: u8, input: &str, offset: usize, expected: impl Into<String>) -> Self {
+        let offset = char_start(input, offset);
+        let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |i| offset + i);
+
+        ParseError::on_line(
+            day,
+            input[..line_start].matches('\n').count() + 1,
+            &input[line_start..line_end],
+            offset - line_start,
+            expected,
+        )
+    }
+
+    /// Points at byte `offset` of `text`, the whole of 1-based line `line`, for parsers
+    /// that read one line at a time. An offset inside a character points at its start.
+    pub fn on_line(
+        day: u8,
+        line: usize,
+        text: &str,
+        offset: usize,
+        expected: impl Into<String>,
+    ) -> Self {
+        let offset = char_start(text, offset);
+        ParseError {
+            day,
+            line,
+            column: text[..offset].chars().count() + 1,
+            snippet: text.trim_end_matches('\r').to_owned(),
+            expected: expected.into(),
+        }
+    }
+
+    /// Points at the start of `rest`, which must be a slice of `input`.
+    pub fn at(day: u8, input: &str, rest: &str, expected: impl Into<String>) -> Self {
+        ParseError::new(day, input, offset(input, rest), expected)
+    }
+
+    /// Points at wherever a nom parser run over `input` gave up.
+    pub fn from_nom(
+        day: u8,
+        input: &str,
+        error: nom::Err<nom::error::Error<&str>>,
+        expected: impl Into<String>,
+    ) -> Self {
+        match error {
+            nom::Err::Error(e) | nom::Err::Failure(e) => {
+                ParseError::at(day, input, e.input, expected)
+            }
+            nom::Err::Incomplete(_) => ParseError::new(day, input, input.len(), expected),
+        }
+    }
+}
+
+/// `offset` clamped to `text` and moved back to the start of the character it falls in.
+fn char_start(text: &str, offset: usize) -> usize {
+    let mut offset = offset.min(text.len());
+    while !text.is_char_boundary(offset) {
+        offset -= 1;
+    }
+    offset
+}
+
+fn offset(input: &str, rest: &str) -> usize {
+    (rest.as_ptr() as usize)
+        .checked_sub(input.as_ptr() as usize)
+        .filter(|&offset| offset <= input.len())
+        .expect("rest must be a slice of input")
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let gutter = self.line.to_string().len();
+        writeln!(
+            f,
+            "Day {} input, line {}, column {}: expected {}",
+            self.day, self.line, self.column, self.expected
+        )?;
+        writeln!(f, "{:gutter$} |", "")?;
+        writeln!(f, "{} | {}", self.line, self.snippet)?;
+        write!(f, "{:gutter$} | {:>column$}", "", "^", column = self.column)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Runs `parser` over `input`, which must be consumed up to trailing whitespace.
+pub fn parse_all<'a, T>(
+    day: u8,
+    input: &'a str,
+    expected: &str,
+    mut parser: impl FnMut(&'a str) -> IResult<&'a str, T>,
+) -> Result<T, ParseError> {
+    match parser(input) {
+        Ok((rest, value)) if rest.trim().is_empty() => Ok(value),
+        Ok((rest, _)) => Err(ParseError::at(day, input, rest.trim_start(), expected)),
+        Err(e) => Err(ParseError::from_nom(day, input, e, expected)),
+    }
+}
+
+/// Runs `parser` over every line of `input`, each of which it must consume completely.
+/// Errors point past any whitespace the parser stopped in front of.
+pub fn parse_lines<'a, T>(
+    day: u8,
+    input: &'a str,
+    expected: &str,
+    mut parser: impl FnMut(&'a str) -> IResult<&'a str, T>,
+) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .map(|line| match parser(line) {
+            Ok(("", value)) => Ok(value),
+            Ok((rest, _)) => Err(ParseError::at(day, input, rest.trim_start(), expected)),
+            Err(e) => Err(ParseError::from_nom(day, input, e, expected)),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::{
+        character::complete::{space1, u32},
+        multi::separated_list1,
+    };
+
+    use super::*;
+
+    #[test]
+    fn points_at_the_offending_column() {
+        let input = "1 2 3\n4 x 6\n";
+        let error = parse_lines(2, input, "a number", separated_list1(space1, u32)).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(error.snippet, "4 x 6");
+        assert_eq!(
+            error.to_string(),
+            "Day 2 input, line 2, column 3: expected a number\n  |\n2 | 4 x 6\n  |   ^"
+        );
+    }
+
+    #[test]
+    fn snaps_offsets_to_characters() {
+        let error = ParseError::on_line(1, 2, "3\u{3000}", 2, "two location IDs");
+        assert_eq!(error.column, 2);
+        assert_eq!(ParseError::new(1, "1\n3\u{3000}", 4, "").column, 2);
+    }
+
+    #[test]
+    fn rejects_unconsumed_input() {
+        let input = "1 2\n\n3";
+        let error = parse_all(7, input, "a number", separated_list1(space1, u32)).unwrap_err();
+        assert_eq!((error.line, error.column), (3, 1));
+        assert_eq!(
+            parse_all(7, "1 2\n", "a number", separated_list1(space1, u32)),
+            Ok(vec![1, 2])
+        );
+    }
+}
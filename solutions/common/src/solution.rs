@@ -0,0 +1,76 @@
+use std::fmt::{Display, Formatter};
+
+use crate::{Answer, Strictness, normalize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl TryFrom<u8> for Part {
+    type Error = anyhow::Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            _ => anyhow::bail!("There is no part {value}"),
+        }
+    }
+}
+
+impl From<Part> for u8 {
+    fn from(part: Part) -> Self {
+        match part {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+/// A day's puzzle: parse the input once, then answer both parts from it.
+///
+/// Malformed input must make these return an error, never panic. The fuzz targets and
+/// the runner's tests hold every day to that.
+pub trait Solution {
+    const DAY: u8;
+
+    type Input;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input>;
+
+    fn part_1(input: &Self::Input) -> anyhow::Result<Answer>;
+
+    fn part_2(input: &Self::Input) -> anyhow::Result<Answer>;
+
+    /// Normalizes raw puzzle input, then parses it.
+    fn load(input: &str, strictness: Strictness) -> anyhow::Result<Self::Input> {
+        Self::parse(&normalize(Self::DAY, input, strictness)?)
+    }
+
+    fn solve(part: Part, input: &str) -> anyhow::Result<Answer> {
+        Self::solve_with(part, input, Strictness::Lenient)
+    }
+
+    fn solve_with(part: Part, input: &str, strictness: Strictness) -> anyhow::Result<Answer> {
+        let input = Self::load(input, strictness)?;
+        match part {
+            Part::One => Self::part_1(&input),
+            Part::Two => Self::part_2(&input),
+        }
+    }
+}
@@ -0,0 +1,57 @@
+//! Times both part 1 distance strategies on random lists of one length over a growing
+//! span of IDs, to show where counting stops beating comparison sorting.
+//!
+//! `cargo run --release -p day_1 --example distance_crossover -- [ids per list]`
+
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use day_1::DistanceStrategy;
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+fn fastest(mut run: impl FnMut()) -> Duration {
+    (0..3)
+        .map(|_| {
+            let start = Instant::now();
+            run();
+            start.elapsed()
+        })
+        .min()
+        .unwrap_or_default()
+}
+
+fn main() {
+    let len: usize = std::env::args()
+        .nth(1)
+        .and_then(|len| len.parse().ok())
+        .unwrap_or(1_000_000);
+    let mut rng = StdRng::seed_from_u64(1);
+
+    println!("{len} IDs per list");
+    println!(
+        "{:>12}  {:>10}  {:>12}  {:>12}  chosen",
+        "span", "span / ids", "comparison", "counting"
+    );
+    let spans = std::iter::successors(Some(1_000_i64), |span| Some(span * 2));
+    for span in spans.take_while(|&span| span <= 128_000_000) {
+        let mut list = || -> Vec<i64> { (0..len).map(|_| rng.random_range(0..span)).collect() };
+        let (left, right) = (list(), list());
+
+        let time = |strategy: DistanceStrategy| {
+            fastest(|| {
+                black_box(strategy.total_distance(black_box(&left), black_box(&right)));
+            })
+        };
+        let comparison = time(DistanceStrategy::Comparison);
+        let counting = time(DistanceStrategy::Counting);
+        println!(
+            "{span:>12}  {:>10.2}  {:>10.1}ms  {:>10.1}ms  {:?}",
+            span as f64 / (2 * len) as f64,
+            comparison.as_secs_f64() * 1e3,
+            counting.as_secs_f64() * 1e3,
+            DistanceStrategy::choose(&left, &right),
+        );
+    }
+}
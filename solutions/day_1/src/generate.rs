@@ -0,0 +1,26 @@
+use common::Generator;
+use rand::{Rng, SeedableRng, rngs::StdRng, seq::IndexedRandom};
+
+use crate::Day1;
+
+impl Generator for Day1 {
+    /// `size` pairs of five digit location IDs, with some right IDs copied from the left
+    /// list so part 2 has something to count.
+    fn generate(seed: u64, size: usize) -> Option<String> {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let left: Vec<u32> = (0..size)
+            .map(|_| rng.random_range(10_000..100_000))
+            .collect();
+        let text = left
+            .iter()
+            .map(|l| {
+                let r = match left.choose(&mut rng) {
+                    Some(&copied) if rng.random_bool(0.3) => copied,
+                    _ => rng.random_range(10_000..100_000),
+                };
+                format!("{l}   {r}\n")
+            })
+            .collect();
+        Some(text)
+    }
+}
@@ -0,0 +1,365 @@
+use std::io::BufRead;
+
+use anyhow::Context;
+use common::{Answer, ParseError, Solution};
+
+mod generate;
+mod stats;
+
+pub use stats::{ColumnReport, Comparison, Multiset};
+
+pub struct Day1;
+
+/// The left and right location lists, in input order.
+pub type Lists = (Vec<i64>, Vec<i64>);
+
+/// Any number of location lists side by side, one column per list.
+pub type Columns = Vec<Vec<i64>>;
+
+/// Reads exactly two location lists, as [`read_columns`] does.
+pub fn read_lists(reader: impl BufRead) -> anyhow::Result<Lists> {
+    Ok(into_lists(read_rows(reader, Some(2))?).unwrap_or_default())
+}
+
+/// Splits exactly two columns into the left and right lists, handing any other number
+/// back untouched.
+fn into_lists(columns: Columns) -> Result<Lists, Columns> {
+    <[Vec<i64>; 2]>::try_from(columns).map(|[left, right]| (left, right))
+}
+
+/// Reads side by side location lists from `reader` a line at a time, reusing one buffer.
+/// IDs may be separated by any whitespace, every row needs as many as the first, and
+/// blank lines are only allowed at the end.
+pub fn read_columns(reader: impl BufRead) -> anyhow::Result<Columns> {
+    read_rows(reader, None)
+}
+
+fn read_rows(mut reader: impl BufRead, mut width: Option<usize>) -> anyhow::Result<Columns> {
+    let mut columns: Columns = vec![];
+    let mut buf = String::new();
+    let mut row = vec![];
+    let mut first_blank = None;
+    for line in 1.. {
+        buf.clear();
+        let read = reader
+            .read_line(&mut buf)
+            .with_context(|| format!("Unable to read line {line} of the location lists"))?;
+        if read == 0 {
+            break;
+        }
+
+        let text = buf.trim_end_matches(['\n', '\r']);
+        let text = match line {
+            1 => text.strip_prefix('\u{feff}').unwrap_or(text),
+            _ => text,
+        };
+        parse_row(line, text, width, &mut row)?;
+        if row.is_empty() {
+            first_blank.get_or_insert(line);
+            continue;
+        }
+        if let Some(blank) = first_blank {
+            return Err(ParseError::on_line(Day1::DAY, blank, "", 0, "location IDs").into());
+        }
+
+        let width = *width.get_or_insert(row.len());
+        columns.resize_with(width, Vec::new);
+        for (column, &id) in columns.iter_mut().zip(&row) {
+            column.push(id);
+        }
+    }
+
+    Ok(columns)
+}
+
+/// Parses the IDs on line number `line` into `row`, leaving it empty if the line is
+/// blank. Once `width` is known every other row must have exactly that many.
+fn parse_row(
+    line: usize,
+    text: &str,
+    width: Option<usize>,
+    row: &mut Vec<i64>,
+) -> Result<(), ParseError> {
+    let error =
+        |offset, expected: &str| ParseError::on_line(Day1::DAY, line, text, offset, expected);
+    let offset = |id: &str| id.as_ptr() as usize - text.as_ptr() as usize;
+
+    row.clear();
+    for id in text.split_whitespace() {
+        if width == Some(row.len()) {
+            return Err(error(offset(id), "the end of the line"));
+        }
+        row.push(id.parse().map_err(|_| error(offset(id), "a location ID"))?);
+    }
+    match width {
+        Some(width) if !row.is_empty() && row.len() < width => {
+            // Just past the whitespace that follows the last ID, if any.
+            let end = text.trim_end().len();
+            let end = text[end..]
+                .chars()
+                .next()
+                .map_or(end, |c| end + c.len_utf8());
+            Err(error(end, &format!("{width} location IDs")))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Part 1's score: how far apart two lists are once both are sorted.
+pub fn total_distance(left: &[i64], right: &[i64]) -> i128 {
+    DistanceStrategy::choose(left, right).total_distance(left, right)
+}
+
+/// Part 2's score: every ID in `left` times how often it appears in `right`.
+pub fn similarity(left: &[i64], right: &Multiset) -> i128 {
+    left.iter()
+        .map(|&id| i128::from(id) * i128::from(right.count(id)))
+        .sum()
+}
+
+/// How part 1 puts the lists in order before pairing up their IDs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DistanceStrategy {
+    /// Sort both lists, for IDs spread over a wide range.
+    Comparison,
+    /// Count each ID in a table spanning the smallest to the largest, which is linear in
+    /// the length of the lists plus that span.
+    Counting,
+}
+
+/// Counting is used while the span of IDs is at most this many times the number of IDs,
+/// as a numerator and denominator. `examples/distance_crossover.rs` finds it stops
+/// winning at around 1.5.
+const COUNTING_SPAN_PER_ID: (u64, u64) = (3, 2);
+
+/// Keeps each count table within 1 GiB.
+const MAX_COUNTING_SPAN: u64 = 1 << 28;
+
+impl DistanceStrategy {
+    /// Picks counting when the IDs are dense enough for their count tables to pay off.
+    pub fn choose(left: &[i64], right: &[i64]) -> Self {
+        let ids = (left.len() + right.len()) as u64;
+        let (per_id, per) = COUNTING_SPAN_PER_ID;
+        match span(left, right) {
+            Some((_, span)) if span <= MAX_COUNTING_SPAN && span * per <= ids * per_id => {
+                DistanceStrategy::Counting
+            }
+            _ => DistanceStrategy::Comparison,
+        }
+    }
+
+    /// Sums the distances between the smallest left and right IDs, the second smallest,
+    /// and so on.
+    pub fn total_distance(self, left: &[i64], right: &[i64]) -> i128 {
+        let mut total = 0;
+        self.pair_up(left, right, |distance, pairs| {
+            total += i128::from(distance) * i128::from(pairs)
+        });
+        total
+    }
+
+    /// Walks the same pairs in order, calling `visit` with each distance and how many
+    /// pairs in a row are that far apart.
+    pub fn pair_up(self, left: &[i64], right: &[i64], visit: impl FnMut(u64, u64)) {
+        match self {
+            DistanceStrategy::Comparison => pair_sorted(left, right, visit),
+            DistanceStrategy::Counting => pair_counted(left, right, visit),
+        }
+    }
+}
+
+/// The smallest ID in either list, and how many values lie from it to the largest.
+fn span(left: &[i64], right: &[i64]) -> Option<(i64, u64)> {
+    let min = *left.iter().chain(right).min()?;
+    let max = *left.iter().chain(right).max()?;
+    Some((min, max.abs_diff(min).saturating_add(1)))
+}
+
+fn pair_sorted(left: &[i64], right: &[i64], mut visit: impl FnMut(u64, u64)) {
+    let mut left = left.to_vec();
+    let mut right = right.to_vec();
+    left.sort_unstable();
+    right.sort_unstable();
+
+    for (l, r) in left.into_iter().zip(right) {
+        visit(l.abs_diff(r), 1);
+    }
+}
+
+/// Each offset into a count table that holds any IDs, in order, with its count.
+fn present(counts: &[u32]) -> impl Iterator<Item = (usize, u32)> + '_ {
+    counts
+        .iter()
+        .enumerate()
+        .filter(|(_, &count)| count > 0)
+        .map(|(offset, &count)| (offset, count))
+}
+
+/// Falls back to sorting when the count tables would be too big.
+fn pair_counted(left: &[i64], right: &[i64], mut visit: impl FnMut(u64, u64)) {
+    let Some((min, span)) = span(left, right) else {
+        return;
+    };
+    if span > MAX_COUNTING_SPAN {
+        return pair_sorted(left, right, visit);
+    }
+    let counts = |ids: &[i64]| {
+        let mut counts = vec![0_u32; span as usize];
+        for id in ids {
+            counts[id.abs_diff(min) as usize] += 1;
+        }
+        counts
+    };
+    let (left, right) = (counts(left), counts(right));
+    let (mut lefts, mut rights) = (present(&left), present(&right));
+
+    // Pair up IDs in order, as many at a time as both sides have of their current value.
+    let (mut l, mut r) = (lefts.next(), rights.next());
+    while let (Some((l_offset, l_count)), Some((r_offset, r_count))) = (l, r) {
+        let pairs = l_count.min(r_count);
+        visit(l_offset.abs_diff(r_offset) as u64, u64::from(pairs));
+        l = if l_count == pairs {
+            lefts.next()
+        } else {
+            Some((l_offset, l_count - pairs))
+        };
+        r = if r_count == pairs {
+            rights.next()
+        } else {
+            Some((r_offset, r_count - pairs))
+        };
+    }
+}
+
+impl Solution for Day1 {
+    type Input = Lists;
+
+    const DAY: u8 = 1;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        read_lists(input.as_bytes())
+    }
+
+    fn part_1((left, right): &Self::Input) -> anyhow::Result<Answer> {
+        Ok(total_distance(left, right).into())
+    }
+
+    fn part_2((left, right): &Self::Input) -> anyhow::Result<Answer> {
+        let right: Multiset = right.iter().copied().collect();
+        Ok(similarity(left, &right).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{Generator, Part};
+
+    use super::*;
+
+    #[test]
+    fn part_1_test() {
+        let input = include_str!("inputs/sample.txt");
+        assert_eq!(Day1::solve(Part::One, input).unwrap(), 11.into());
+    }
+
+    #[test]
+    fn part_2_test() {
+        let input = include_str!("inputs/sample.txt");
+        assert_eq!(Day1::solve(Part::Two, input).unwrap(), 31.into());
+    }
+
+    #[test]
+    fn distance_strategies_agree() {
+        let cases = [
+            (vec![3, -4, 2, 1, 3, 3], vec![4, 3, 5, 3, 9, 3]),
+            (vec![i64::MIN, 0, i64::MAX], vec![i64::MAX, i64::MIN, 7]),
+            (vec![5, 1, 1], vec![2]),
+            (vec![], vec![]),
+        ];
+        for seed in 0..4 {
+            let (left, right) = Day1::parse(&Day1::generate(seed, 200).unwrap()).unwrap();
+            for (left, right) in cases.iter().cloned().chain([(left, right)]) {
+                assert_eq!(
+                    DistanceStrategy::Counting.total_distance(&left, &right),
+                    DistanceStrategy::Comparison.total_distance(&left, &right),
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn chooses_counting_for_dense_ids() {
+        let dense: Vec<i64> = (0..1000).rev().collect();
+        assert_eq!(
+            DistanceStrategy::choose(&dense, &dense),
+            DistanceStrategy::Counting
+        );
+        let sparse: Vec<i64> = dense.iter().map(|id| id * 1000).collect();
+        assert_eq!(
+            DistanceStrategy::choose(&sparse, &dense),
+            DistanceStrategy::Comparison
+        );
+
+        let shifted: Vec<i64> = dense.iter().map(|id| id + 1500).collect();
+        assert_eq!(
+            DistanceStrategy::choose(&dense, &shifted),
+            DistanceStrategy::Counting
+        );
+        let shifted: Vec<i64> = dense.iter().map(|id| id + 2500).collect();
+        assert_eq!(
+            DistanceStrategy::choose(&dense, &shifted),
+            DistanceStrategy::Comparison
+        );
+    }
+
+    fn read_error(input: &str) -> ParseError {
+        read_lists(input.as_bytes())
+            .unwrap_err()
+            .downcast()
+            .unwrap()
+    }
+
+    #[test]
+    fn parse_error_test() {
+        let error = read_error("3   4\n4   x\n");
+        assert_eq!((error.day, error.line, error.column), (1, 2, 5));
+        assert_eq!(error.snippet, "4   x");
+
+        let error = read_error("3   4\n4\n5   6\n");
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(error.expected, "2 location IDs");
+
+        let error = read_error("3   4\n\n5   6\n");
+        assert_eq!((error.line, error.column), (2, 1));
+
+        let error = read_error("1 2\n3\u{3000}\n");
+        assert_eq!((error.line, error.column), (2, 3));
+        assert!(Day1::solve(Part::One, "1 2\n3\u{3000}\n").is_err());
+    }
+
+    #[test]
+    fn read_columns_test() {
+        let input = "1 2 3\n4\t5   6\n";
+        assert_eq!(
+            read_columns(input.as_bytes()).unwrap(),
+            [vec![1, 4], vec![2, 5], vec![3, 6]]
+        );
+
+        let error: ParseError = read_columns("1 2 3\n4 5\n".as_bytes())
+            .unwrap_err()
+            .downcast()
+            .unwrap();
+        assert_eq!((error.line, error.column), (2, 4));
+        assert_eq!(error.expected, "3 location IDs");
+        assert_eq!(read_error("1 2\n3 4 5\n").column, 5);
+    }
+
+    #[test]
+    fn read_lists_test() {
+        let input = "\u{feff}3\t4\r\n9000000000  \t -2  \n\n";
+        assert_eq!(
+            read_lists(input.as_bytes()).unwrap(),
+            (vec![3, 9_000_000_000], vec![4, -2])
+        );
+    }
+}
@@ -0,0 +1,252 @@
+use std::{
+    cmp::Ordering,
+    collections::{BTreeMap, HashMap},
+};
+
+use crate::{DistanceStrategy, similarity, total_distance};
+
+/// How many times each ID appears in a list.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Multiset(HashMap<i64, u64>);
+
+impl Multiset {
+    pub fn count(&self, id: i64) -> u64 {
+        self.0.get(&id).copied().unwrap_or_default()
+    }
+
+    /// The number of IDs, counting repeats.
+    pub fn len(&self) -> u64 {
+        self.0.values().sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Each distinct ID with its count, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (i64, u64)> + '_ {
+        self.0.iter().map(|(&id, &count)| (id, count))
+    }
+
+    /// The IDs, counting repeats, that both sets share.
+    pub fn intersection_len(&self, other: &Multiset) -> u64 {
+        self.iter()
+            .map(|(id, count)| count.min(other.count(id)))
+            .sum()
+    }
+
+    /// The distinct IDs that `other` lacks, in ascending order.
+    pub fn missing_from(&self, other: &Multiset) -> Vec<i64> {
+        let mut ids: Vec<_> = self
+            .iter()
+            .filter(|&(id, _)| other.count(id) == 0)
+            .map(|(id, _)| id)
+            .collect();
+        ids.sort_unstable();
+        ids
+    }
+}
+
+impl FromIterator<i64> for Multiset {
+    fn from_iter<I: IntoIterator<Item = i64>>(ids: I) -> Self {
+        let mut counts = HashMap::new();
+        for id in ids {
+            *counts.entry(id).or_default() += 1;
+        }
+        Multiset(counts)
+    }
+}
+
+/// An audit of how the two location lists differ, beyond part 1's total distance.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    /// How many of the pairs part 1 matches up are each distance apart.
+    pub distances: BTreeMap<u64, u64>,
+    /// The middle pair distance, halfway between the two middle ones for an even count.
+    pub median_distance: Option<f64>,
+    pub max_distance: Option<u64>,
+    /// Shared IDs over all IDs, both counting repeats. Two empty lists overlap fully.
+    pub jaccard: f64,
+    /// Distinct IDs that never appear in the right list, ascending.
+    pub only_left: Vec<i64>,
+    /// Distinct IDs that never appear in the left list, ascending.
+    pub only_right: Vec<i64>,
+}
+
+impl Comparison {
+    pub fn new(left: &[i64], right: &[i64]) -> Self {
+        let mut distances = BTreeMap::new();
+        DistanceStrategy::choose(left, right).pair_up(left, right, |distance, pairs| {
+            *distances.entry(distance).or_default() += pairs
+        });
+
+        let (left, right): (Multiset, Multiset) = (
+            left.iter().copied().collect(),
+            right.iter().copied().collect(),
+        );
+
+        Comparison {
+            median_distance: median(&distances),
+            max_distance: distances.keys().next_back().copied(),
+            distances,
+            jaccard: jaccard(&left, &right),
+            only_left: left.missing_from(&right),
+            only_right: right.missing_from(&left),
+        }
+    }
+}
+
+/// See [`Comparison::jaccard`].
+fn jaccard(left: &Multiset, right: &Multiset) -> f64 {
+    let shared = left.intersection_len(right);
+    match left.len() + right.len() - shared {
+        0 => 1.0,
+        union => shared as f64 / union as f64,
+    }
+}
+
+/// Both part scores, and how much they overlap, between every pair of side by side
+/// location lists.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnReport {
+    /// `distances[i][j]` is part 1's total distance between columns `i` and `j`.
+    pub distances: Vec<Vec<i128>>,
+    /// `similarities[i][j]` is part 2's score of column `i` against column `j`.
+    pub similarities: Vec<Vec<i128>>,
+    /// `overlaps[i][j]` is the Jaccard index of columns `i` and `j`, as in
+    /// [`Comparison::jaccard`].
+    pub overlaps: Vec<Vec<f64>>,
+}
+
+impl ColumnReport {
+    pub fn new(columns: &[Vec<i64>]) -> Self {
+        let sets: Vec<Multiset> = columns
+            .iter()
+            .map(|column| column.iter().copied().collect())
+            .collect();
+        let n = columns.len();
+
+        ColumnReport {
+            distances: matrix(n, |i, j| total_distance(&columns[i], &columns[j])),
+            similarities: matrix(n, |i, j| similarity(&columns[i], &sets[j])),
+            overlaps: matrix(n, |i, j| jaccard(&sets[i], &sets[j])),
+        }
+    }
+
+    /// Every pair of different columns, lower index first.
+    fn pairs(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let n = self.overlaps.len();
+        (0..n).flat_map(move |i| (i + 1..n).map(move |j| (i, j)))
+    }
+
+    /// Orders pairs of columns from least to most similar: by overlap, then with the
+    /// pair further apart as the less similar one.
+    fn compare(&self, (i, j): (usize, usize), (k, l): (usize, usize)) -> Ordering {
+        self.overlaps[i][j]
+            .total_cmp(&self.overlaps[k][l])
+            .then(self.distances[k][l].cmp(&self.distances[i][j]))
+    }
+
+    /// The pair of columns that overlap the most, then lie closest, the first on ties.
+    pub fn most_similar(&self) -> Option<(usize, usize)> {
+        self.pairs().reduce(|best, pair| {
+            if self.compare(pair, best).is_gt() {
+                pair
+            } else {
+                best
+            }
+        })
+    }
+
+    /// The pair of columns that overlap the least, then lie furthest apart, the first on
+    /// ties.
+    pub fn least_similar(&self) -> Option<(usize, usize)> {
+        self.pairs().reduce(|worst, pair| {
+            if self.compare(pair, worst).is_lt() {
+                pair
+            } else {
+                worst
+            }
+        })
+    }
+}
+
+fn matrix<T>(n: usize, score: impl Fn(usize, usize) -> T) -> Vec<Vec<T>> {
+    (0..n)
+        .map(|i| (0..n).map(|j| score(i, j)).collect())
+        .collect()
+}
+
+fn median(distances: &BTreeMap<u64, u64>) -> Option<f64> {
+    let pairs: u64 = distances.values().sum();
+    // The distance of the pair at `index` once they are all sorted by distance.
+    let nth = |index: u64| {
+        let mut seen = 0;
+        distances.iter().find_map(|(&distance, &count)| {
+            seen += count;
+            (seen > index).then_some(distance)
+        })
+    };
+
+    let low = nth(pairs.checked_sub(1)? / 2)?;
+    let high = nth(pairs / 2)?;
+    Some((low as f64 + high as f64) / 2.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compares_the_sample_lists() {
+        let comparison = Comparison::new(&[3, 4, 2, 1, 3, 3], &[4, 3, 5, 3, 9, 3]);
+        assert_eq!(
+            comparison.distances,
+            BTreeMap::from([(0, 1), (1, 2), (2, 2), (5, 1)])
+        );
+        assert_eq!(comparison.median_distance, Some(1.5));
+        assert_eq!(comparison.max_distance, Some(5));
+        assert_eq!(comparison.jaccard, 0.5);
+        assert_eq!(comparison.only_left, [1, 2]);
+        assert_eq!(comparison.only_right, [5, 9]);
+    }
+
+    #[test]
+    fn reports_on_every_column_pair() {
+        let left = vec![3, 4, 2, 1, 3, 3];
+        let right = vec![4, 3, 5, 3, 9, 3];
+        let report = ColumnReport::new(&[left.clone(), right, left]);
+        assert_eq!(report.distances, [[0, 11, 0], [11, 0, 11], [0, 11, 0]]);
+        assert_eq!(report.similarities[0][1], 31);
+        assert_eq!(report.similarities[0][2], 34);
+        assert_eq!(report.overlaps[0][1], 0.5);
+        assert_eq!(report.most_similar(), Some((0, 2)));
+        assert_eq!(report.least_similar(), Some((0, 1)));
+        assert_eq!(ColumnReport::new(&[vec![1]]).most_similar(), None);
+
+        let report = ColumnReport::new(&[vec![1, 2], vec![1, 2], vec![1000, 5], vec![1000, 6]]);
+        assert_eq!(report.most_similar(), Some((0, 1)));
+        assert_eq!(report.least_similar(), Some((0, 3)));
+    }
+
+    #[test]
+    fn breaks_overlap_ties_by_distance() {
+        let report = ColumnReport::new(&[vec![1, 2], vec![100, 200], vec![3, 4]]);
+        assert_eq!(report.overlaps[0][1], 0.0);
+        assert_eq!(report.most_similar(), Some((0, 2)));
+        assert_eq!(report.least_similar(), Some((0, 1)));
+    }
+
+    #[test]
+    fn handles_empty_and_identical_lists() {
+        let empty = Comparison::new(&[], &[]);
+        assert_eq!((empty.median_distance, empty.max_distance), (None, None));
+        assert_eq!(empty.jaccard, 1.0);
+
+        let same = Comparison::new(&[7, 1, 7], &[7, 7, 1]);
+        assert_eq!(same.distances, BTreeMap::from([(0, 3)]));
+        assert_eq!(same.median_distance, Some(0.0));
+        assert_eq!(same.jaccard, 1.0);
+        assert!(same.only_left.is_empty() && same.only_right.is_empty());
+    }
+}
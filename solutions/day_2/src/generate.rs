@@ -0,0 +1,31 @@
+use common::Generator;
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+use crate::Day2;
+
+impl Generator for Day2 {
+    /// `size` reports of 5 to 8 levels that mostly climb or fall gently, with the odd step
+    /// that breaks the rules.
+    fn generate(seed: u64, size: usize) -> Option<String> {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let text = (0..size)
+            .map(|_| {
+                let sign = if rng.random_bool(0.5) { 1 } else { -1 };
+                let mut level: i32 = rng.random_range(10..90);
+                let mut levels = vec![level];
+                for _ in 1..rng.random_range(5..=8) {
+                    let step = if rng.random_bool(0.1) {
+                        rng.random_range(-2..=6)
+                    } else {
+                        rng.random_range(1..=3)
+                    };
+                    level = (level + sign * step).max(1);
+                    levels.push(level);
+                }
+                let levels: Vec<_> = levels.iter().map(i32::to_string).collect();
+                levels.join(" ") + "\n"
+            })
+            .collect();
+        Some(text)
+    }
+}
@@ -0,0 +1,210 @@
+use common::{Answer, ParseError, Solution, parse::parse_lines};
+use nom::{
+    character::complete::{char, i32},
+    multi::separated_list1,
+};
+
+mod generate;
+
+pub struct Day2;
+
+/// Parses one report of levels per line.
+pub fn parse_input(input: &str) -> Result<Vec<Vec<i32>>, ParseError> {
+    parse_lines(
+        Day2::DAY,
+        input,
+        "levels separated by single spaces",
+        separated_list1(char(' '), i32),
+    )
+}
+
+/// The rules a report of levels has to follow to count as safe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SafetyPolicy {
+    /// The smallest allowed change between neighbouring levels, plateaus aside.
+    pub min_step: u32,
+    /// The largest allowed change between neighbouring levels.
+    pub max_step: u32,
+    /// Whether neighbouring levels may be equal.
+    pub allow_plateaus: bool,
+    /// Whether every change must go the same way as the first one.
+    pub strictly_monotonic: bool,
+}
+
+impl SafetyPolicy {
+    /// The puzzle's rules: strictly increasing or decreasing by 1 to 3 each step.
+    pub const PUZZLE: SafetyPolicy = SafetyPolicy {
+        min_step: 1,
+        max_step: 3,
+        allow_plateaus: false,
+        strictly_monotonic: true,
+    };
+
+    /// Rejects policies no report can follow, whose `min_step` is above their `max_step`.
+    pub fn validate(&self) -> anyhow::Result<()> {
+        if self.min_step > self.max_step {
+            anyhow::bail!(
+                "A safety policy's min_step {} is above its max_step {}",
+                self.min_step,
+                self.max_step
+            );
+        }
+        Ok(())
+    }
+
+    /// Whether `levels` follows every rule. Reports with fewer than two levels always do.
+    pub fn is_safe(&self, levels: &[i32]) -> bool {
+        let mut rising = None;
+        levels.windows(2).all(|pair| {
+            let step = pair[0].abs_diff(pair[1]);
+            if step == 0 {
+                return self.allow_plateaus;
+            }
+            let up = pair[0] < pair[1];
+            (self.min_step..=self.max_step).contains(&step)
+                && (!self.strictly_monotonic || *rising.get_or_insert(up) == up)
+        })
+    }
+
+    /// Whether `levels` is safe, or would be with any single level removed.
+    pub fn is_safe_dampened(&self, levels: &[i32]) -> bool {
+        if self.is_safe(levels) {
+            return true;
+        }
+        (0..levels.len()).any(|i| {
+            let mut levels = levels.to_vec();
+            levels.remove(i);
+            self.is_safe(&levels)
+        })
+    }
+}
+
+impl Default for SafetyPolicy {
+    fn default() -> Self {
+        SafetyPolicy::PUZZLE
+    }
+}
+
+/// Counts the reports that are safe under `policy`, once it has been validated.
+pub fn count_safe(reports: &[Vec<i32>], policy: &SafetyPolicy) -> anyhow::Result<usize> {
+    policy.validate()?;
+    Ok(reports
+        .iter()
+        .filter(|levels| policy.is_safe(levels))
+        .count())
+}
+
+/// Counts the reports that are safe under `policy` with at most one level removed, once
+/// it has been validated.
+pub fn count_safe_dampened(reports: &[Vec<i32>], policy: &SafetyPolicy) -> anyhow::Result<usize> {
+    policy.validate()?;
+    Ok(reports
+        .iter()
+        .filter(|levels| policy.is_safe_dampened(levels))
+        .count())
+}
+
+/// Whether a report is safe under the puzzle's rules.
+pub fn check_line(arr: &[i32]) -> bool {
+    SafetyPolicy::PUZZLE.is_safe(arr)
+}
+
+impl Solution for Day2 {
+    type Input = Vec<Vec<i32>>;
+
+    const DAY: u8 = 2;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_1(input: &Self::Input) -> anyhow::Result<Answer> {
+        Ok(count_safe(input, &SafetyPolicy::PUZZLE)?.into())
+    }
+
+    fn part_2(input: &Self::Input) -> anyhow::Result<Answer> {
+        Ok(count_safe_dampened(input, &SafetyPolicy::PUZZLE)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Part;
+
+    use super::*;
+
+    #[test]
+    fn part_1_test() {
+        let input = include_str!("inputs/sample.txt");
+        assert_eq!(Day2::solve(Part::One, input).unwrap(), 2.into());
+    }
+
+    #[test]
+    fn part_2_test() {
+        let input = include_str!("inputs/sample.txt");
+        assert_eq!(Day2::solve(Part::Two, input).unwrap(), 4.into());
+    }
+
+    #[test]
+    fn check_line_test() {
+        assert!(check_line(&[7, 6, 4, 2, 1]));
+        assert!(!check_line(&[1, 2, 7, 8, 9]));
+        assert!(!check_line(&[8, 6, 4, 4, 1]));
+        assert!(check_line(&[5]));
+        assert!(!check_line(&[i32::MIN, i32::MAX]));
+    }
+
+    #[test]
+    fn safety_policy_test() {
+        let lenient = SafetyPolicy {
+            max_step: 5,
+            allow_plateaus: true,
+            ..SafetyPolicy::PUZZLE
+        };
+        assert!(lenient.is_safe(&[1, 2, 7, 7, 9]));
+        assert!(!lenient.is_safe(&[1, 2, 8]));
+        assert!(!lenient.is_safe(&[4, 4, 5, 3]));
+
+        let zigzag = SafetyPolicy {
+            strictly_monotonic: false,
+            ..SafetyPolicy::PUZZLE
+        };
+        assert!(zigzag.is_safe(&[1, 3, 2, 4]));
+        assert!(!zigzag.is_safe(&[1, 3, 3, 4]));
+
+        let steep = SafetyPolicy {
+            min_step: 2,
+            ..SafetyPolicy::PUZZLE
+        };
+        assert!(!steep.is_safe(&[1, 2, 4]));
+        assert!(steep.is_safe_dampened(&[1, 2, 3, 5]));
+
+        let reports = parse_input(include_str!("inputs/sample.txt")).unwrap();
+        assert_eq!(count_safe(&reports, &lenient).unwrap(), 5);
+        assert_eq!(count_safe_dampened(&reports, &lenient).unwrap(), 6);
+    }
+
+    #[test]
+    fn inverted_policy_test() {
+        let inverted = SafetyPolicy {
+            min_step: 3,
+            max_step: 1,
+            ..SafetyPolicy::PUZZLE
+        };
+        let error = inverted.validate().unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .contains("min_step 3 is above its max_step 1")
+        );
+        assert!(count_safe(&[vec![1, 2]], &inverted).is_err());
+        assert!(count_safe_dampened(&[vec![1, 2]], &inverted).is_err());
+        assert!(SafetyPolicy::PUZZLE.validate().is_ok());
+    }
+
+    #[test]
+    fn parse_error_test() {
+        let error = parse_input("7 6 4\n1 2  7").unwrap_err();
+        assert_eq!((error.day, error.line, error.column), (2, 2, 6));
+    }
+}
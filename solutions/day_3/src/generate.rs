@@ -0,0 +1,45 @@
+use common::Generator;
+use rand::{Rng, SeedableRng, rngs::StdRng, seq::IndexedRandom};
+
+use crate::Day3;
+
+const NOISE: &[&str] = &[
+    "!", "@", "#", "$", "%", "^", "&", "*", "(", ")", "[", "]", "{", "}", "<", ">", "?", "/", ",",
+    ";", ":", "'", "+", "-", "_", "~", " ", "from()", "what()", "why()", "who()", "select()",
+    "where()", "how()", "when()", "mul", "do", "don't",
+];
+
+impl Generator for Day3 {
+    /// `size` fragments of corrupted memory, mixing real instructions with noise and
+    /// near misses, starting with one real `mul` so the input always parses.
+    fn generate(seed: u64, size: usize) -> Option<String> {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut text = String::new();
+        let count = size.max(1);
+        for i in 0..count {
+            let (x, y): (u32, u32) = (rng.random_range(1..1000), rng.random_range(1..1000));
+            let fragment = match rng.random_range(0..10) {
+                _ if i == 0 => format!("mul({x},{y})"),
+                0..=2 => format!("mul({x},{y})"),
+                3 => "do()".to_owned(),
+                4 => "don't()".to_owned(),
+                5 => [
+                    "mul({x},{y}]",
+                    "mul[{x},{y})",
+                    "mul({x}, {y})",
+                    "mul ( {x},{y} )",
+                ]
+                .choose(&mut rng)
+                .unwrap()
+                .replace("{x}", &x.to_string())
+                .replace("{y}", &y.to_string()),
+                _ => NOISE.choose(&mut rng).unwrap().to_string(),
+            };
+            text.push_str(&fragment);
+            if i % 50 == 49 && i + 1 < count {
+                text.push('\n');
+            }
+        }
+        Some(text + "\n")
+    }
+}
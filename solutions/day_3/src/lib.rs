@@ -0,0 +1,118 @@
+use common::{Answer, ParseError, Solution};
+use nom::{
+    IResult, Parser,
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{self, anychar},
+    combinator::value,
+    multi::{many_till, many1},
+    sequence::{delimited, separated_pair},
+};
+
+mod generate;
+
+pub struct Day3;
+
+/// Parses a single `mul(x,y)` instruction.
+pub fn mul(input: &str) -> IResult<&str, Instruction> {
+    let (input, _) = tag("mul")(input)?;
+    let (input, pair) = delimited(
+        tag("("),
+        separated_pair(complete::i32, tag(","), complete::i32),
+        tag(")"),
+    )(input)?;
+
+    Ok((input, Instruction::Mul(pair.0, pair.1)))
+}
+
+#[derive(Debug, Clone)]
+pub enum Instruction {
+    Mul(i32, i32),
+    Do,
+    Dont,
+}
+
+/// Parses a `mul(x,y)`, `do()` or `don't()` instruction.
+pub fn instruction(input: &str) -> IResult<&str, Instruction> {
+    alt((
+        value(Instruction::Dont, tag("don't()")),
+        value(Instruction::Do, tag("do()")),
+        mul,
+    ))(input)
+}
+
+impl Solution for Day3 {
+    type Input = Vec<Instruction>;
+
+    const DAY: u8 = 3;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        let (_, value) = many1(many_till(anychar, instruction).map(|(_discard, ins)| ins))(input)
+            .map_err(|e| {
+            ParseError::from_nom(
+                Self::DAY,
+                input,
+                e,
+                "a `mul(x,y)`, `do()` or `don't()` instruction",
+            )
+        })?;
+
+        Ok(value)
+    }
+
+    fn part_1(input: &Self::Input) -> anyhow::Result<Answer> {
+        let result: i128 = input
+            .iter()
+            .map(|i| match i {
+                Instruction::Mul(x, y) => i128::from(*x) * i128::from(*y),
+                _ => 0,
+            })
+            .sum();
+
+        Ok(result.into())
+    }
+
+    fn part_2(input: &Self::Input) -> anyhow::Result<Answer> {
+        let (_, result) = input
+            .iter()
+            .fold((true, 0), |(enabled, result), ins| match ins {
+                Instruction::Mul(a, b) => {
+                    if enabled {
+                        (enabled, result + i128::from(*a) * i128::from(*b))
+                    } else {
+                        (enabled, result)
+                    }
+                }
+                Instruction::Do => (true, result),
+                Instruction::Dont => (false, result),
+            });
+
+        Ok(result.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Part;
+
+    use super::*;
+
+    #[test]
+    fn part_1_test() {
+        let input = include_str!("inputs/sample.txt");
+        assert_eq!(Day3::solve(Part::One, input).unwrap(), 161.into());
+    }
+
+    #[test]
+    fn part_2_test() {
+        let input = include_str!("inputs/sample_2.txt");
+        assert_eq!(Day3::solve(Part::Two, input).unwrap(), 48.into());
+    }
+
+    #[test]
+    fn solves_runtime_input() {
+        let input = format!("{}{}", "mul(2,4)don't()", "mul(5,5)do()mul(3,3)");
+        assert_eq!(Day3::solve(Part::One, &input).unwrap(), 42.into());
+        assert_eq!(Day3::solve(Part::Two, &input).unwrap(), 17.into());
+    }
+}
@@ -0,0 +1,22 @@
+use common::Generator;
+use rand::{SeedableRng, rngs::StdRng, seq::IndexedRandom};
+
+use crate::Day4;
+
+impl Generator for Day4 {
+    /// A `size` by `size` word search of the letters in XMAS.
+    fn generate(seed: u64, size: usize) -> Option<String> {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let side = size.max(1);
+        let text = (0..side)
+            .map(|_| {
+                let mut row: String = (0..side)
+                    .map(|_| *['X', 'M', 'A', 'S'].choose(&mut rng).unwrap())
+                    .collect();
+                row.push('\n');
+                row
+            })
+            .collect();
+        Some(text)
+    }
+}
@@ -0,0 +1,74 @@
+use common::{Answer, Compass, Grid, Solution};
+
+mod generate;
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Grid<char>;
+
+    const DAY: u8 = 4;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(Grid::parse(Self::DAY, input, "a letter", Some)?)
+    }
+
+    fn part_1(grid: &Self::Input) -> anyhow::Result<Answer> {
+        let count = grid
+            .iter()
+            .filter(|(_, &c)| c == 'X')
+            .flat_map(|(pos, _)| {
+                Compass::ALL.into_iter().filter(move |compass| {
+                    grid.ray(pos, compass.offset())
+                        .map(|(_, &c)| c)
+                        .take(4)
+                        .eq("XMAS".chars())
+                })
+            })
+            .count();
+
+        Ok(count.into())
+    }
+
+    fn part_2(grid: &Self::Input) -> anyhow::Result<Answer> {
+        let is_mas = |a: Option<&char>, b: Option<&char>| {
+            matches!((a, b), (Some('M'), Some('S')) | (Some('S'), Some('M')))
+        };
+
+        let count = grid
+            .iter()
+            .filter(|(pos, &c)| {
+                c == 'A'
+                    && is_mas(
+                        grid.get(pos + Compass::NW.offset()),
+                        grid.get(pos + Compass::SE.offset()),
+                    )
+                    && is_mas(
+                        grid.get(pos + Compass::NE.offset()),
+                        grid.get(pos + Compass::SW.offset()),
+                    )
+            })
+            .count();
+
+        Ok(count.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Part;
+
+    use super::*;
+
+    #[test]
+    fn part_1_test() {
+        let input = include_str!("inputs/sample.txt");
+        assert_eq!(Day4::solve(Part::One, input).unwrap(), 18.into());
+    }
+
+    #[test]
+    fn part_2_test() {
+        let input = include_str!("inputs/sample.txt");
+        assert_eq!(Day4::solve(Part::Two, input).unwrap(), 9.into());
+    }
+}
@@ -0,0 +1,36 @@
+use common::Generator;
+use rand::{Rng, SeedableRng, rngs::StdRng, seq::SliceRandom};
+
+use crate::Day5;
+
+impl Generator for Day5 {
+    /// A rule for every pair of 49 distinct pages, followed by `size` odd length updates,
+    /// about half of them already in order.
+    fn generate(seed: u64, size: usize) -> Option<String> {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut order: Vec<u32> = (10..100).collect();
+        order.shuffle(&mut rng);
+        order.truncate(49);
+
+        let mut rules = vec![];
+        for (i, before) in order.iter().enumerate() {
+            for after in &order[i + 1..] {
+                rules.push(format!("{before}|{after}\n"));
+            }
+        }
+        rules.shuffle(&mut rng);
+
+        let mut text = rules.concat() + "\n";
+        for _ in 0..size.max(1) {
+            let len = rng.random_range(2..=11) * 2 + 1;
+            let mut picked = rand::seq::index::sample(&mut rng, order.len(), len).into_vec();
+            if rng.random_bool(0.5) {
+                picked.sort_unstable();
+            }
+            let pages: Vec<_> = picked.iter().map(|&i| order[i].to_string()).collect();
+            text.push_str(&pages.join(","));
+            text.push('\n');
+        }
+        Some(text)
+    }
+}
@@ -0,0 +1,198 @@
+use std::collections::HashMap;
+
+use common::{Answer, ParseError, Solution, parse::parse_all};
+use nom::{
+    IResult,
+    bytes::complete::tag,
+    character::{complete, complete::line_ending},
+    multi::{fold_many1, separated_list1},
+    sequence::{separated_pair, terminated},
+};
+
+mod generate;
+#[cfg(test)]
+mod reference;
+
+pub struct Day5;
+
+/// Page ordering rules, keyed by the page that must come first.
+pub type Rules = HashMap<u32, Vec<u32>>;
+
+pub fn rules(input: &str) -> IResult<&str, Rules> {
+    fold_many1(
+        terminated(
+            separated_pair(complete::u32, tag("|"), complete::u32),
+            line_ending,
+        ),
+        HashMap::default,
+        |mut acc: Rules, (page, after)| {
+            acc.entry(page)
+                .and_modify(|afters| {
+                    afters.push(after);
+                })
+                .or_insert(vec![after]);
+            acc
+        },
+    )(input)
+}
+
+pub fn updates(input: &str) -> IResult<&str, Vec<Vec<u32>>> {
+    separated_list1(line_ending, separated_list1(tag(","), complete::u32))(input)
+}
+
+/// Parses the ordering rules and the list of updates that follow them.
+pub fn parse(input: &str) -> Result<(Rules, Vec<Vec<u32>>), ParseError> {
+    parse_all(
+        Day5::DAY,
+        input,
+        "`X|Y` rules, a blank line, then comma separated updates",
+        separated_pair(rules, line_ending, updates),
+    )
+}
+
+impl Solution for Day5 {
+    type Input = (Rules, Vec<Vec<u32>>);
+
+    const DAY: u8 = 5;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(parse(input)?)
+    }
+
+    fn part_1((rules, updates): &Self::Input) -> anyhow::Result<Answer> {
+        let results: Vec<usize> = updates
+            .iter()
+            .enumerate()
+            .filter_map(|(index, original_update)| {
+                let (&first, rest) = original_update.split_first()?;
+                let mut current_item = first;
+                let mut update = rest;
+                let mut before_pages = &original_update[0..0];
+
+                while before_pages.len() != original_update.len() {
+                    if let Some(pages_that_must_come_after) = rules.get(&current_item) {
+                        if !pages_that_must_come_after
+                            .iter()
+                            .all(|page| !before_pages.contains(page))
+                        {
+                            return None;
+                        }
+                    }
+
+                    before_pages = &original_update[0..(before_pages.len() + 1)];
+
+                    if let Some(page) = update.first() {
+                        current_item = *page;
+                        update = &update[1..];
+                    }
+                }
+
+                Some(index)
+            })
+            .collect();
+
+        let result: u64 = results
+            .iter()
+            .map(|index| {
+                let middle = updates[*index].len() / 2;
+                u64::from(updates[*index][middle])
+            })
+            .sum();
+
+        Ok(result.into())
+    }
+
+    fn part_2((rules, updates): &Self::Input) -> anyhow::Result<Answer> {
+        let results: Vec<usize> = updates
+            .iter()
+            .enumerate()
+            .filter_map(|(index, original_update)| {
+                let (&first, rest) = original_update.split_first()?;
+                let mut current_item = first;
+                let mut update = rest;
+                let mut before_pages = &original_update[0..0];
+
+                while before_pages.len() != original_update.len() {
+                    if let Some(pages_that_must_come_after) = rules.get(&current_item) {
+                        if !pages_that_must_come_after
+                            .iter()
+                            .all(|page| !before_pages.contains(page))
+                        {
+                            return Some(index);
+                        }
+                    }
+
+                    before_pages = &original_update[0..(before_pages.len() + 1)];
+
+                    if let Some(page) = update.first() {
+                        current_item = *page;
+                        update = &update[1..];
+                    }
+                }
+
+                None
+            })
+            .collect();
+        let sorted_results: Vec<_> = results
+            .iter()
+            .map(|index| {
+                let pages = &updates[*index];
+                let mut update = pages.clone();
+                // Ranking by how many pages must come first agrees with the rules whenever
+                // they order every pair, and unlike a comparator built from them it cannot
+                // panic when they contradict each other.
+                update.sort_by_cached_key(|page| {
+                    pages
+                        .iter()
+                        .filter(|&before| {
+                            rules.get(before).is_some_and(|after| after.contains(page))
+                        })
+                        .count()
+                });
+                update
+            })
+            .collect();
+
+        let result: u64 = sorted_results
+            .iter()
+            .map(|result| {
+                let middle = result.len() / 2;
+                u64::from(result[middle])
+            })
+            .sum();
+
+        Ok(result.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Part;
+
+    use super::*;
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(
+            Day5::solve(Part::One, include_str!("inputs/sample.txt")).unwrap(),
+            143.into()
+        );
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(
+            Day5::solve(Part::Two, include_str!("inputs/sample.txt")).unwrap(),
+            123.into()
+        );
+    }
+
+    #[test]
+    fn large_pages_do_not_overflow() {
+        let input = "1|2\n\n4294967295\n4294967295\n";
+        assert_eq!(
+            Day5::solve(Part::One, input).unwrap(),
+            8589934590_u64.into()
+        );
+    }
+}
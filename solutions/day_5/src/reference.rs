@@ -0,0 +1,114 @@
+//! Slow but obvious versions of both parts that compare every pair of pages.
+
+use crate::Rules;
+
+fn must_precede(rules: &Rules, before: u32, after: u32) -> bool {
+    rules
+        .get(&before)
+        .is_some_and(|pages| pages.contains(&after))
+}
+
+/// Whether no page in `update` has to come before a page printed ahead of it.
+fn is_ordered(rules: &Rules, update: &[u32]) -> bool {
+    (0..update.len())
+        .all(|i| (i + 1..update.len()).all(|j| !must_precede(rules, update[j], update[i])))
+}
+
+/// Repeatedly takes the first page that no remaining page has to precede.
+fn reorder(rules: &Rules, update: &[u32]) -> Vec<u32> {
+    let mut remaining = update.to_vec();
+    let mut ordered = vec![];
+    while !remaining.is_empty() {
+        let next = (0..remaining.len())
+            .find(|&i| {
+                remaining
+                    .iter()
+                    .all(|&other| !must_precede(rules, other, remaining[i]))
+            })
+            .unwrap_or(0);
+        ordered.push(remaining.remove(next));
+    }
+
+    ordered
+}
+
+pub fn part_1(rules: &Rules, updates: &[Vec<u32>]) -> u64 {
+    updates
+        .iter()
+        .filter(|update| is_ordered(rules, update))
+        .map(|update| u64::from(update[update.len() / 2]))
+        .sum()
+}
+
+pub fn part_2(rules: &Rules, updates: &[Vec<u32>]) -> u64 {
+    updates
+        .iter()
+        .filter(|update| !is_ordered(rules, update))
+        .map(|update| u64::from(reorder(rules, update)[update.len() / 2]))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{Answer, Part, Solution};
+    use proptest::{prelude::*, sample::subsequence};
+
+    use super::*;
+    use crate::{Day5, parse};
+
+    /// A shuffled order of distinct pages, a rule for every pair of them, and odd length
+    /// updates drawn from them, the way the puzzle guarantees.
+    fn puzzle() -> impl Strategy<Value = String> {
+        prop::collection::btree_set(10..100_u32, 3..12)
+            .prop_flat_map(|pages| Just(pages.into_iter().collect::<Vec<_>>()).prop_shuffle())
+            .prop_flat_map(|order| {
+                let len = order.len();
+                let update = subsequence(order.clone(), 1..=len)
+                    .prop_filter("updates have a middle page", |update| update.len() % 2 == 1)
+                    .prop_shuffle();
+                (Just(order), prop::collection::vec(update, 1..8))
+            })
+            .prop_map(|(order, updates)| {
+                let mut text = String::new();
+                for (i, before) in order.iter().enumerate() {
+                    for after in &order[i + 1..] {
+                        text.push_str(&format!("{before}|{after}\n"));
+                    }
+                }
+                text.push('\n');
+                for update in updates {
+                    let pages: Vec<_> = update.iter().map(u32::to_string).collect();
+                    text.push_str(&pages.join(","));
+                    text.push('\n');
+                }
+                text
+            })
+    }
+
+    #[test]
+    fn reference_matches_sample() {
+        let (rules, updates) = parse(include_str!("inputs/sample.txt")).unwrap();
+        assert_eq!(part_1(&rules, &updates), 143);
+        assert_eq!(part_2(&rules, &updates), 123);
+    }
+
+    proptest! {
+        #[test]
+        fn part_1_matches_reference(input in puzzle()) {
+            let (rules, updates) = parse(&input).unwrap();
+            prop_assert_eq!(
+                Day5::solve(Part::One, &input).unwrap(),
+                Answer::from(part_1(&rules, &updates))
+            );
+        }
+
+        #[test]
+        fn part_2_matches_reference(input in puzzle()) {
+            let (rules, updates) = parse(&input).unwrap();
+            prop_assert_eq!(
+                Day5::solve(Part::Two, &input).unwrap(),
+                Answer::from(part_2(&rules, &updates))
+            );
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use std::collections::HashSet;
+
+use common::{Direction, Generator, Grid};
+use rand::{Rng, SeedableRng, rngs::StdRng, seq::IndexedRandom};
+
+use crate::{Day6, Piece, find_guard, parse_input};
+
+/// Whether the guard walks off the map rather than patrolling in a loop forever.
+fn escapes(grid: &Grid<Piece>) -> bool {
+    let Some((mut pos, mut direction)) = find_guard(grid) else {
+        return false;
+    };
+    let mut seen = HashSet::new();
+    while seen.insert((pos, direction)) {
+        let next = pos + direction.offset();
+        match grid.get(next) {
+            None => return true,
+            Some(Piece::Obstruction) => direction = direction.clockwise(),
+            Some(_) => pos = next,
+        }
+    }
+    false
+}
+
+impl Generator for Day6 {
+    /// A `size` by `size` lab with scattered obstructions and a guard who eventually
+    /// leaves it, as the puzzle promises.
+    fn generate(seed: u64, size: usize) -> Option<String> {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let side = size.max(1) as i32;
+        loop {
+            let mut cells: Vec<Piece> = (0..side * side)
+                .map(|_| {
+                    if rng.random_bool(0.1) {
+                        Piece::Obstruction
+                    } else {
+                        Piece::Open
+                    }
+                })
+                .collect();
+            let guard = rng.random_range(0..cells.len());
+            cells[guard] = Piece::Guard(*Direction::ALL.choose(&mut rng).unwrap());
+
+            let text: String = (0..side)
+                .map(|y| {
+                    let row: String = (0..side)
+                        .map(|x| cells[(y * side + x) as usize].to_string())
+                        .collect();
+                    row + "\n"
+                })
+                .collect();
+            if parse_input(&text).is_ok_and(|grid| escapes(&grid)) {
+                return Some(text);
+            }
+        }
+    }
+}
@@ -0,0 +1,161 @@
+use std::{collections::HashSet, fmt::Formatter};
+
+use common::{Answer, Direction, Grid, IVec2, ParseError, Solution};
+
+mod generate;
+
+pub struct Day6;
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Piece {
+    Open,
+    Obstruction,
+    Guard(Direction),
+}
+
+impl std::fmt::Display for Piece {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Piece::Open => write!(f, "."),
+            Piece::Obstruction => write!(f, "#"),
+            Piece::Guard(direction) => write!(f, "{}", direction),
+        }
+    }
+}
+
+/// Finds the guard's position and the direction they are facing.
+pub fn find_guard(grid: &Grid<Piece>) -> Option<(IVec2, Direction)> {
+    grid.iter().find_map(|(pos, piece)| match piece {
+        Piece::Guard(direction) => Some((pos, *direction)),
+        _ => None,
+    })
+}
+
+pub fn parse_piece(c: char) -> Option<Piece> {
+    match c {
+        '.' => Some(Piece::Open),
+        '#' => Some(Piece::Obstruction),
+        _ => Direction::from_arrow(c).map(Piece::Guard),
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Grid<Piece>, ParseError> {
+    Grid::parse(
+        Day6::DAY,
+        input,
+        "one of `.`, `#`, `^`, `>`, `v` or `<`",
+        parse_piece,
+    )
+}
+
+impl Solution for Day6 {
+    type Input = Grid<Piece>;
+
+    const DAY: u8 = 6;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_1(grid: &Self::Input) -> anyhow::Result<Answer> {
+        let Some((mut pos, mut direction)) = find_guard(grid) else {
+            anyhow::bail!("Where is the guard?");
+        };
+        let mut seen: HashSet<IVec2> = HashSet::default();
+        let mut states: HashSet<(IVec2, Direction)> = HashSet::default();
+        while states.insert((pos, direction)) {
+            let next = pos + direction.offset();
+            match grid.get(next) {
+                None => return Ok(seen.len().into()),
+                Some(Piece::Obstruction) => direction = direction.clockwise(),
+                Some(_) => {
+                    pos = next;
+                    seen.insert(pos);
+                }
+            }
+        }
+
+        anyhow::bail!("The guard patrols in a loop and never leaves the map")
+    }
+
+    fn part_2(grid: &Self::Input) -> anyhow::Result<Answer> {
+        let mut grid = grid.clone();
+        let mut loop_obstruction_count = 0;
+        let Some((start, direction)) = find_guard(&grid) else {
+            anyhow::bail!("Where is the guard?");
+        };
+
+        for obstruction in grid.positions() {
+            let mut seen: HashSet<(IVec2, Direction)> = HashSet::default();
+            if grid[obstruction] != Piece::Open {
+                continue;
+            }
+            grid[obstruction] = Piece::Obstruction;
+
+            // Restart on each loop
+            let mut pos = start;
+            let mut direction = direction;
+
+            // start moving the guard until exit or seen
+            loop {
+                let next = pos + direction.offset();
+                match grid.get(next) {
+                    // guard exited, try a new one
+                    None => break,
+                    Some(Piece::Obstruction) => direction = direction.clockwise(),
+                    Some(_) => pos = next,
+                }
+                // turning counts too, or a guard boxed in on all sides spins forever
+                if !seen.insert((pos, direction)) {
+                    loop_obstruction_count += 1;
+                    break;
+                }
+            }
+
+            grid[obstruction] = Piece::Open;
+        }
+
+        Ok(loop_obstruction_count.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{Part, Strictness};
+
+    use super::*;
+
+    #[test]
+    fn part_1_test() {
+        let input = include_str!("inputs/sample.txt");
+        let res = Day6::solve(Part::One, input).unwrap();
+        assert_eq!(res, 41.into());
+    }
+
+    #[test]
+    fn part_2_test() {
+        let input = include_str!("inputs/sample.txt");
+        let res = Day6::solve(Part::Two, input).unwrap();
+        assert_eq!(res, 6.into());
+    }
+
+    #[test]
+    fn find_guard_test() {
+        let grid = parse_input("..#\n.<.").unwrap();
+        assert_eq!(find_guard(&grid), Some((IVec2::ONE, Direction::Left)));
+    }
+
+    #[test]
+    fn crlf_and_trailing_lines_test() {
+        let input = include_str!("inputs/sample.txt").replace('\n', "\r\n") + "\r\n\r\n";
+        assert_eq!(Day6::solve(Part::One, &input).unwrap(), 41.into());
+        assert!(Day6::solve_with(Part::One, &input, Strictness::Strict).is_err());
+    }
+
+    #[test]
+    fn boxed_in_guard_test() {
+        let input = ".#.\n#^#\n.#.";
+        assert!(Day6::solve(Part::One, input).is_err());
+        assert_eq!(Day6::solve(Part::Two, input).unwrap(), 4.into());
+    }
+}
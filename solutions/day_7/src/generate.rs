@@ -0,0 +1,43 @@
+use common::Generator;
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+use crate::Day7;
+
+/// No combination of operands with this many digits in total can overflow a `u64`.
+const MAX_DIGITS: u32 = 19;
+
+impl Generator for Day7 {
+    /// `size` equations of 2 to 10 operands, about half of which some mix of `+`, `*` and
+    /// `||` makes true.
+    fn generate(seed: u64, size: usize) -> Option<String> {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let text = (0..size)
+            .map(|_| {
+                let count = rng.random_range(2..=10);
+                let mut digits = 0;
+                let mut operands: Vec<u64> = vec![];
+                while operands.len() < count && digits < MAX_DIGITS {
+                    let len = rng.random_range(1..=3.min(MAX_DIGITS - digits));
+                    digits += len;
+                    operands.push(rng.random_range(10u64.pow(len - 1)..10u64.pow(len)));
+                }
+
+                let mut value = operands[0];
+                for &operand in &operands[1..] {
+                    value = match rng.random_range(0..3) {
+                        0 => value + operand,
+                        1 => value * operand,
+                        _ => value * 10u64.pow(operand.ilog10() + 1) + operand,
+                    };
+                }
+                if rng.random_bool(0.5) {
+                    value = rng.random_range(1..=value);
+                }
+
+                let operands: Vec<_> = operands.iter().map(u64::to_string).collect();
+                format!("{value}: {}\n", operands.join(" "))
+            })
+            .collect();
+        Some(text)
+    }
+}
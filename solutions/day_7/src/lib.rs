@@ -0,0 +1,139 @@
+use common::{Answer, ParseError, Solution, parse::parse_lines};
+use itertools::Itertools;
+use nom::{
+    bytes::complete::tag,
+    character::complete::{self, space1},
+    combinator::verify,
+    multi::separated_list1,
+    sequence::separated_pair,
+};
+
+mod generate;
+
+pub struct Day7;
+
+/// The most operands an equation may have. Every combination of operators is tried, so
+/// each one more triples the work.
+pub const MAX_OPERANDS: usize = 12;
+
+/// Parses each calibration equation into its test value and operands.
+pub fn parse_input(input: &str) -> Result<Vec<(u64, Vec<u64>)>, ParseError> {
+    parse_lines(
+        Day7::DAY,
+        input,
+        &format!("a test value, `: ` and up to {MAX_OPERANDS} space separated operands"),
+        separated_pair(
+            complete::u64,
+            tag(": "),
+            verify(
+                separated_list1(space1, complete::u64),
+                |operands: &Vec<u64>| operands.len() <= MAX_OPERANDS,
+            ),
+        ),
+    )
+}
+
+const PART_1_OPERATIONS: [char; 2] = ['+', '*'];
+const PART_2_OPERATIONS: [&str; 3] = ["+", "*", "||"];
+
+impl Solution for Day7 {
+    type Input = Vec<(u64, Vec<u64>)>;
+
+    const DAY: u8 = 7;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_1(lines: &Self::Input) -> anyhow::Result<Answer> {
+        let result: i128 = lines
+            .iter()
+            .filter_map(|(test, numbers)| {
+                let operator_count = numbers.len() - 1;
+                (0..operator_count)
+                    .map(|_| PART_1_OPERATIONS)
+                    .multi_cartesian_product()
+                    .any(|seq| {
+                        let result = numbers[1..].iter().zip(seq).try_fold(
+                            numbers[0],
+                            |acc, (&cur, operator)| match operator {
+                                '*' => acc.checked_mul(cur),
+                                '+' => acc.checked_add(cur),
+                                _ => None,
+                            },
+                        );
+                        result == Some(*test)
+                    })
+                    .then_some(i128::from(*test))
+            })
+            .sum();
+
+        Ok(result.into())
+    }
+
+    fn part_2(lines: &Self::Input) -> anyhow::Result<Answer> {
+        let result: i128 = lines
+            .iter()
+            .filter_map(|(test, numbers)| {
+                let operator_count = numbers.len() - 1;
+                (0..operator_count)
+                    .map(|_| PART_2_OPERATIONS)
+                    .multi_cartesian_product()
+                    .any(|seq| {
+                        let result = numbers[1..].iter().zip(seq).try_fold(
+                            numbers[0],
+                            |acc, (&cur, operator)| match operator {
+                                "*" => acc.checked_mul(cur),
+                                "+" => acc.checked_add(cur),
+                                "||" => format!("{acc}{cur}").parse::<u64>().ok(),
+                                _ => None,
+                            },
+                        );
+                        result == Some(*test)
+                    })
+                    .then_some(i128::from(*test))
+            })
+            .sum();
+
+        Ok(result.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Part;
+
+    use super::*;
+
+    #[test]
+    fn test_part_1() {
+        let input = include_str!("inputs/sample.txt");
+        let answer = Day7::solve(Part::One, input).unwrap();
+        assert_eq!(answer, 3749.into());
+    }
+
+    #[test]
+    fn test_part_2() {
+        let input = include_str!("inputs/sample.txt");
+        let answer = Day7::solve(Part::Two, input).unwrap();
+        assert_eq!(answer, 11387.into());
+    }
+
+    #[test]
+    fn overflow_test() {
+        let input = "18446744073709551615: 18446744073709551615 2\n9: 9999999999 99999999999";
+        assert_eq!(Day7::solve(Part::One, input).unwrap(), 0.into());
+        assert_eq!(Day7::solve(Part::Two, input).unwrap(), 0.into());
+    }
+
+    #[test]
+    fn too_many_operands_test() {
+        let operands = ["1"; MAX_OPERANDS];
+        let input = format!("12: {}", operands.join(" "));
+        assert_eq!(Day7::solve(Part::One, &input).unwrap(), 12.into());
+
+        let input = format!("3190: 10 19\n13: {} 1", operands.join(" "));
+        let error = parse_input(&input).unwrap_err();
+        assert_eq!((error.day, error.line, error.column), (7, 2, 5));
+    }
+}
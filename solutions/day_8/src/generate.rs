@@ -0,0 +1,33 @@
+use common::Generator;
+use rand::{Rng, SeedableRng, rngs::StdRng, seq::SliceRandom};
+
+use crate::Day8;
+
+const FREQUENCIES: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+
+impl Generator for Day8 {
+    /// A `size` by `size` map with a handful of frequencies, each on 2 to 4 antennas.
+    fn generate(seed: u64, size: usize) -> Option<String> {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let side = size.max(1);
+        let mut cells = vec!['.'; side * side];
+        let mut free: Vec<usize> = (0..cells.len()).collect();
+        free.shuffle(&mut rng);
+
+        let mut frequencies: Vec<char> = FREQUENCIES.chars().collect();
+        frequencies.shuffle(&mut rng);
+        for &frequency in &frequencies[..rng.random_range(1..=8)] {
+            for _ in 0..rng.random_range(2..=4) {
+                if let Some(i) = free.pop() {
+                    cells[i] = frequency;
+                }
+            }
+        }
+
+        let text = cells
+            .chunks(side)
+            .map(|row| row.iter().collect::<String>() + "\n")
+            .collect();
+        Some(text)
+    }
+}
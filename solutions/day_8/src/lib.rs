@@ -0,0 +1,130 @@
+use std::iter::successors;
+
+use common::{Answer, Grid, IVec2, ParseError, Solution};
+use itertools::Itertools;
+
+mod generate;
+
+pub struct Day8;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Antenna {
+    pub label: char,
+    pub pos: IVec2,
+}
+
+#[derive(Clone, Debug)]
+pub struct Map {
+    pub grid: Grid<char>,
+    pub antennas: Vec<Antenna>,
+}
+
+/// Reads the map and collects every antenna on it.
+pub fn parse_input(input: &str) -> Result<Map, ParseError> {
+    let grid = Grid::parse(Day8::DAY, input, "a map cell", Some)?;
+    let antennas = grid
+        .iter()
+        .filter(|(_, &c)| c != '.')
+        .map(|(pos, &label)| Antenna { label, pos })
+        .collect();
+
+    Ok(Map { grid, antennas })
+}
+
+impl Solution for Day8 {
+    type Input = Map;
+
+    const DAY: u8 = 8;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_1(map: &Self::Input) -> anyhow::Result<Answer> {
+        let mut antenna = map.antennas.clone();
+
+        antenna.sort_by_key(|a| a.label);
+
+        let result = antenna
+            .chunk_by(|a, b| a.label == b.label)
+            .flat_map(|chunk| {
+                chunk.iter().combinations(2).flat_map(|c| {
+                    let diff = c[0].pos - c[1].pos;
+                    [c[0].pos + diff, c[1].pos - diff]
+                })
+            })
+            .filter(|pos| map.grid.contains(*pos))
+            .unique()
+            .count();
+
+        Ok(result.into())
+    }
+
+    fn part_2(map: &Self::Input) -> anyhow::Result<Answer> {
+        let mut antenna = map.antennas.clone();
+
+        antenna.sort_by_key(|a| a.label);
+
+        let result = antenna
+            .chunk_by(|a, b| a.label == b.label)
+            .flat_map(|chunk| {
+                chunk
+                    .iter()
+                    .combinations(2)
+                    .flat_map(|c| {
+                        let diff = c[0].pos - c[1].pos;
+
+                        let first: Vec<_> = successors(Some(c[0].pos), |pos| {
+                            let new_pos = pos + diff;
+                            if map.grid.contains(*pos) {
+                                Some(new_pos)
+                            } else {
+                                None
+                            }
+                        })
+                        .collect();
+
+                        let second = successors(Some(c[1].pos), |pos| {
+                            let new_pos = pos - diff;
+                            if map.grid.contains(*pos) {
+                                Some(new_pos)
+                            } else {
+                                None
+                            }
+                        })
+                        .collect();
+
+                        [first, second]
+                    })
+                    .flatten()
+            })
+            .filter(|pos| map.grid.contains(*pos))
+            .unique()
+            .count();
+
+        Ok(result.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Part;
+
+    use super::*;
+
+    #[test]
+    fn part_1_test() {
+        let input = include_str!("inputs/sample.txt");
+        let answer = Day8::solve(Part::One, input).unwrap();
+
+        assert_eq!(answer, 14.into())
+    }
+
+    #[test]
+    fn part_2_test() {
+        let input = include_str!("inputs/sample.txt");
+        let answer = Day8::solve(Part::Two, input).unwrap();
+
+        assert_eq!(answer, 34.into())
+    }
+}
@@ -0,0 +1,19 @@
+use common::Generator;
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+use crate::Day9;
+
+impl Generator for Day9 {
+    /// A disk map of `size` files of 1 to 9 blocks, separated by gaps of 0 to 9.
+    fn generate(seed: u64, size: usize) -> Option<String> {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut text = String::new();
+        for i in 0..size.max(1) {
+            if i > 0 {
+                text.push_str(&rng.random_range(0..=9).to_string());
+            }
+            text.push_str(&rng.random_range(1..=9).to_string());
+        }
+        Some(text + "\n")
+    }
+}
@@ -0,0 +1,197 @@
+use common::{Answer, ParseError, Solution};
+
+mod generate;
+#[cfg(test)]
+mod reference;
+
+pub struct Day9;
+
+struct Chunk {
+    uncompressed_index: usize,
+    count: usize,
+    file_id: usize,
+}
+
+impl Solution for Day9 {
+    type Input = Vec<u32>;
+
+    const DAY: u8 = 9;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        let digits = input
+            .trim_end()
+            .char_indices()
+            .map(|(i, c)| {
+                c.to_digit(10)
+                    .ok_or_else(|| ParseError::new(Self::DAY, input, i, "a disk map digit"))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(digits)
+    }
+
+    fn part_1(input: &Self::Input) -> anyhow::Result<Answer> {
+        let high: u32 = input.iter().sum();
+        let mut rev = (0..input.len())
+            .rev()
+            .zip(input.iter().rev())
+            .scan(high, |base, (compressed_index, c)| {
+                let num_indicies = *c;
+                *base -= num_indicies;
+
+                Some((*base..(*base + num_indicies)).rev().filter_map(move |i| {
+                    (compressed_index % 2 == 0).then_some((i, compressed_index / 2))
+                }))
+            })
+            .flatten();
+
+        let mut base_index = 0;
+        let mut sum = 0;
+        let mut last_uncompressed_index = u32::MAX;
+
+        'disk: for (compressed_index, c) in input.iter().enumerate() {
+            let num_indicies = *c as usize;
+            let file_id = compressed_index / 2;
+
+            for uncompressed_index in base_index..(base_index + num_indicies) {
+                if uncompressed_index >= last_uncompressed_index as usize {
+                    break;
+                }
+                if compressed_index % 2 == 0 {
+                    sum += uncompressed_index * file_id;
+                } else {
+                    // Every block left of here is already counted, so once the next block
+                    // from the end is too there is nothing left to move.
+                    let Some((rev_uncompressed_index, file_id)) =
+                        rev.next().filter(|(i, _)| *i as usize > uncompressed_index)
+                    else {
+                        break 'disk;
+                    };
+                    sum += uncompressed_index * file_id;
+                    last_uncompressed_index = rev_uncompressed_index;
+                }
+            }
+
+            base_index += num_indicies;
+        }
+
+        Ok(sum.into())
+    }
+
+    fn part_2(input: &Self::Input) -> anyhow::Result<Answer> {
+        let high_index: usize = input.iter().map(|c| *c as usize).sum();
+
+        let uncompressed_reversed = (0..input.len()).rev().zip(input.iter().rev());
+        let reverse = uncompressed_reversed
+            .scan(high_index, |base_index, (compressed_index, c)| {
+                let num_indices = *c as usize;
+                *base_index -= num_indices;
+
+                if compressed_index % 2 == 0 {
+                    Some(Some(Chunk {
+                        uncompressed_index: *base_index,
+                        count: num_indices,
+                        file_id: compressed_index / 2,
+                    }))
+                } else {
+                    Some(None)
+                }
+            })
+            .flatten();
+        // .filter_map(|v| v);
+
+        // (uncompressed_index, space_count)
+        let mut empties = input
+            .iter()
+            .enumerate()
+            .fold(
+                (0, vec![]),
+                |(mut uncompressed_index, mut empties), (compressed_index, c)| {
+                    let num_indices = *c as usize;
+                    if compressed_index % 2 != 0 {
+                        empties.push((uncompressed_index, num_indices))
+                    }
+                    uncompressed_index += num_indices;
+                    (uncompressed_index, empties)
+                },
+            )
+            .1;
+
+        // let mut moved_ids: Vec<usize> = vec![];
+        let mut moved_chunks: Vec<Chunk> = vec![];
+        for chunk in reverse {
+            let Some(empty) = empties.iter_mut().find(|(i, empty_space)| {
+                chunk.count <= *empty_space && *i < chunk.uncompressed_index
+            }) else {
+                continue;
+            };
+
+            // moved_ids.push(chunk.file_id);
+            moved_chunks.push(Chunk {
+                uncompressed_index: empty.0,
+                ..chunk
+            });
+            empty.0 += chunk.count;
+            empty.1 -= chunk.count;
+        }
+
+        let mut base_index = 0;
+        let mut sum = 0;
+
+        for (compressed_index, c) in input.iter().enumerate() {
+            let num_indices = *c as usize;
+            let file_id = compressed_index / 2;
+
+            for uncompressed_index in base_index..(base_index + num_indices) {
+                if compressed_index % 2 == 0
+                    && !moved_chunks.iter().any(|chunk| chunk.file_id == file_id)
+                {
+                    sum += uncompressed_index * file_id;
+                }
+            }
+
+            base_index += num_indices;
+        }
+
+        for chunk in moved_chunks.iter() {
+            for index in chunk.uncompressed_index..(chunk.uncompressed_index + chunk.count) {
+                sum += index * chunk.file_id;
+            }
+        }
+
+        Ok(sum.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Part;
+
+    use super::*;
+
+    #[test]
+    fn test_part_1() {
+        let input = include_str!("inputs/sample.txt");
+        let answer = Day9::solve(Part::One, input).unwrap();
+        assert_eq!(answer, 1928.into());
+    }
+
+    #[test]
+    fn test_part_2() {
+        let input = include_str!("inputs/sample.txt");
+        let answer = Day9::solve(Part::Two, input).unwrap();
+        assert_eq!(answer, 2858.into());
+    }
+
+    #[test]
+    fn parse_error_test() {
+        let error = Day9::parse("2333x\n").unwrap_err();
+        let error = error.downcast::<ParseError>().unwrap();
+        assert_eq!((error.line, error.column), (1, 5));
+    }
+
+    #[test]
+    fn gap_past_the_last_file_test() {
+        assert_eq!(Day9::part_1(&vec![1, 1, 1, 3, 1, 4, 1]).unwrap(), 11.into());
+    }
+}
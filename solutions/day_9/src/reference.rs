@@ -0,0 +1,104 @@
+//! Slow but obvious versions of both parts that lay the whole disk out block by block.
+
+/// One entry per block, holding the id of the file stored there.
+fn expand(disk_map: &[u32]) -> Vec<Option<usize>> {
+    disk_map
+        .iter()
+        .enumerate()
+        .flat_map(|(i, &len)| {
+            let block = (i % 2 == 0).then_some(i / 2);
+            std::iter::repeat_n(block, len as usize)
+        })
+        .collect()
+}
+
+fn checksum(blocks: &[Option<usize>]) -> usize {
+    blocks
+        .iter()
+        .enumerate()
+        .filter_map(|(i, block)| block.map(|id| i * id))
+        .sum()
+}
+
+/// Moves blocks one at a time from the end of the disk into the leftmost free block.
+pub fn part_1(disk_map: &[u32]) -> usize {
+    let mut blocks = expand(disk_map);
+    while let (Some(free), Some(last)) = (
+        blocks.iter().position(Option::is_none),
+        blocks.iter().rposition(Option::is_some),
+    ) {
+        if last < free {
+            break;
+        }
+        blocks.swap(free, last);
+    }
+
+    checksum(&blocks)
+}
+
+/// Moves whole files, highest id first, into the leftmost gap before them that fits.
+pub fn part_2(disk_map: &[u32]) -> usize {
+    let mut blocks = expand(disk_map);
+    let files = disk_map.len().div_ceil(2);
+    for id in (0..files).rev() {
+        let Some(start) = blocks.iter().position(|&block| block == Some(id)) else {
+            continue;
+        };
+        let len = blocks[start..]
+            .iter()
+            .take_while(|&&block| block == Some(id))
+            .count();
+
+        let gap = (0..start).find(|&i| blocks[i..i + len].iter().all(Option::is_none));
+        if let Some(gap) = gap {
+            for i in 0..len {
+                blocks.swap(gap + i, start + i);
+            }
+        }
+    }
+
+    checksum(&blocks)
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{Answer, Solution};
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::Day9;
+
+    /// Disk maps the way the puzzle writes them: files of 1 to 9 blocks, gaps of 0 to 9.
+    fn disk_map() -> impl Strategy<Value = Vec<u32>> {
+        (
+            1..=9_u32,
+            prop::collection::vec((0..=9_u32, 1..=9_u32), 0..30),
+        )
+            .prop_map(|(first, rest)| {
+                let mut disk_map = vec![first];
+                for (gap, file) in rest {
+                    disk_map.extend([gap, file]);
+                }
+                disk_map
+            })
+    }
+
+    #[test]
+    fn reference_matches_sample() {
+        let disk_map = Day9::parse(include_str!("inputs/sample.txt")).unwrap();
+        assert_eq!(part_1(&disk_map), 1928);
+        assert_eq!(part_2(&disk_map), 2858);
+    }
+
+    proptest! {
+        #[test]
+        fn part_1_matches_reference(disk_map in disk_map()) {
+            prop_assert_eq!(Day9::part_1(&disk_map).unwrap(), Answer::from(part_1(&disk_map)));
+        }
+
+        #[test]
+        fn part_2_matches_reference(disk_map in disk_map()) {
+            prop_assert_eq!(Day9::part_2(&disk_map).unwrap(), Answer::from(part_2(&disk_map)));
+        }
+    }
+}